@@ -2,28 +2,46 @@
 #![warn(missing_docs)]
 
 #[cfg(feature = "image")]
-use image::{load_from_memory, DynamicImage};
+use image::{load_from_memory, DynamicImage, ImageOutputFormat};
 
+#[cfg(feature = "image")]
+use std::{collections::HashMap, io::Cursor, sync::Mutex};
+
+use std::{
+    borrow::Cow,
+    io,
+    path::PathBuf,
+    sync::Arc,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use futures_util::{stream, Stream, StreamExt};
 use http_cache_reqwest::{CACacheManager, Cache, CacheMode, HttpCache, HttpCacheOptions};
 use reqwest::Client;
 use reqwest_middleware::{ClientBuilder as MiddlewareClientBuilder, ClientWithMiddleware};
 use serde::de::DeserializeOwned;
+use serde_json::Value;
 use url::Url;
 
 #[cfg(test)]
 use mockito;
 
-#[cfg(feature = "image")]
 use crate::models::shared::HasImage;
+#[cfg(feature = "image")]
+use crate::models::shared::Image;
 
 use crate::{
+    fetch_options::{accept_language, check_empty_dataset, CACHE_STATUS_HEADER, DATA_FILES},
+    integrity::AsyncChecksumMiddleware,
+    locale_fallback::{fill_missing_names, has_missing_names},
     models::{
-        champion::ChampionWrapper,
+        challenges::{Challenge, ChallengeTier},
+        champion::{ChampionWrapper, Spell},
         tft::{self, Arenas, Augments, HeroAugments, Queues, Regalia, Tacticians, Traits},
         Challenges, Champion, Champions, ChampionsFull, Items, Maps, MissionAssets, ProfileIcons,
-        Runes, SpellBuffs, SummonerSpells, Translations,
+        Realm, Runes, SpellBuffs, Stickers, SummonerSpells, Translations,
     },
-    ClientError,
+    ClientError, EmptyDatasets, FetchOptions, FetchStats,
 };
 
 #[derive(Clone)]
@@ -37,7 +55,20 @@ pub struct AsyncClientBuilder {
     server: String,
     agent: Option<ClientAgent>,
     cache: Option<String>,
+    local_dir: Option<String>,
+    local_preprocessor: Option<LocalPreprocessor>,
     version: Option<String>,
+    locale: String,
+    locale_fallback: Option<String>,
+    empty_datasets: EmptyDatasets,
+    concurrency: usize,
+    verify_checksums: bool,
+    max_idle_connections_per_host: Option<usize>,
+    max_idle_age: Option<Duration>,
+    timeout: Option<Duration>,
+    tcp_keepalive: Option<Duration>,
+    versions_ttl: Duration,
+    revalidate: bool,
 }
 
 ///
@@ -99,8 +130,60 @@ impl AsyncClientBuilder {
             server: "https://ddragon.leagueoflegends.com".to_owned(),
             agent: None,
             cache: None,
+            local_dir: None,
+            local_preprocessor: None,
             version: None,
+            locale: "en_US".to_owned(),
+            locale_fallback: None,
+            empty_datasets: EmptyDatasets::Allow,
+            concurrency: 8,
+            verify_checksums: false,
+            max_idle_connections_per_host: None,
+            max_idle_age: None,
+            timeout: None,
+            tcp_keepalive: None,
+            versions_ttl: Duration::from_secs(5 * 60),
+            revalidate: false,
+        }
+    }
+
+    /// Creates a [AsyncClientBuilder] configured from the environment:
+    ///
+    /// - `DDRAGON_CACHE_DIR` sets the [cache](Self::cache) directory.
+    /// - `DDRAGON_VERSION` pins the [version](Self::version).
+    /// - `DDRAGON_LOCALE` sets the [locale](Self::locale).
+    ///
+    /// Unset variables leave the corresponding option at its default, so with
+    /// none set this is the same as [new](Self::new) (no caching, latest
+    /// version, `en_US`). Options set on the returned builder take precedence
+    /// over the environment.
+    ///
+    /// ```no_run
+    /// # tokio_test::block_on(async {
+    /// use ddragon::AsyncClientBuilder;
+    ///
+    /// // DDRAGON_CACHE_DIR is ignored here, since a cache is set explicitly.
+    /// let client = AsyncClientBuilder::from_env().cache("./cache").build().await.unwrap();
+    /// # })
+    /// ```
+    pub fn from_env() -> Self {
+        Self::from_vars(|name| std::env::var(name).ok())
+    }
+
+    /// Does the work of [from_env](Self::from_env), looking variables up with
+    /// `var` so it can be tested without touching the real environment.
+    fn from_vars(var: impl Fn(&str) -> Option<String>) -> Self {
+        let mut builder = Self::new();
+        if let Some(dir) = var("DDRAGON_CACHE_DIR") {
+            builder = builder.cache(&dir);
+        }
+        if let Some(version) = var("DDRAGON_VERSION") {
+            builder = builder.version(&version);
         }
+        if let Some(locale) = var("DDRAGON_LOCALE") {
+            builder = builder.locale(&locale);
+        }
+        builder
     }
 
     /// Configures a custom [ClientWithMiddleware] for making network requests.
@@ -123,6 +206,55 @@ impl AsyncClientBuilder {
         self
     }
 
+    /// Configures the client to read data from a local mirror of ddragon
+    /// instead of the network, which is useful for offline development and
+    /// CI. The directory should be laid out like the ddragon host, e.g.
+    /// `<dir>/api/versions.json` and `<dir>/cdn/<version>/data/en_US/*.json`.
+    /// If the mirror has no `api/versions.json` (such as an extracted
+    /// `dragontail` archive moved into a `cdn` folder), the version must be
+    /// set with `.version()`.
+    ///
+    /// ```no_run
+    /// # tokio_test::block_on(async {
+    /// use ddragon::AsyncClientBuilder;
+    ///
+    /// let client = AsyncClientBuilder::new().local_dir("./ddragon").build().await.unwrap();
+    /// # })
+    /// ```
+    pub fn local_dir(mut self, dir: &str) -> Self {
+        self.local_dir = Some(dir.to_owned());
+        self
+    }
+
+    /// Configures a function that transforms the raw bytes of every JSON file
+    /// read from the [local mirror](Self::local_dir) before it is parsed, e.x.
+    /// to tolerate quirks in hand-edited files. Data fetched over the network
+    /// is always parsed strictly.
+    ///
+    /// ```no_run
+    /// # tokio_test::block_on(async {
+    /// use ddragon::AsyncClientBuilder;
+    ///
+    /// // Hand-edited files sometimes gain a byte order mark, which serde_json rejects.
+    /// let client = AsyncClientBuilder::new()
+    ///     .local_dir("./ddragon")
+    ///     .local_preprocessor(|bytes| match bytes.strip_prefix(b"\xEF\xBB\xBF") {
+    ///         Some(stripped) => stripped.to_vec(),
+    ///         None => bytes,
+    ///     })
+    ///     .build()
+    ///     .await
+    ///     .unwrap();
+    /// # })
+    /// ```
+    pub fn local_preprocessor(
+        mut self,
+        preprocess: impl Fn(Vec<u8>) -> Vec<u8> + Send + Sync + 'static,
+    ) -> Self {
+        self.local_preprocessor = Some(Arc::new(preprocess));
+        self
+    }
+
     /// Configure the ddragon version for making requests. Normally this should
     /// not be needed, as the latest version is always used.
     pub fn version(mut self, version: &str) -> Self {
@@ -130,6 +262,108 @@ impl AsyncClientBuilder {
         self
     }
 
+    /// Configure the locale data is fetched in, e.x. `ko_KR`. Defaults to
+    /// `en_US`.
+    pub fn locale(mut self, locale: &str) -> Self {
+        self.locale = locale.to_owned();
+        self
+    }
+
+    /// Configure a locale to fall back to when data fetched in the
+    /// [locale](Self::locale) has empty or missing names, which happens for
+    /// newly added entries that have not been translated yet.
+    ///
+    /// Only names are filled in from the fallback, everything else is kept
+    /// as fetched. Note that whenever a gap is found the whole dataset is
+    /// fetched a second time in the fallback locale, doubling the network
+    /// cost of that request.
+    pub fn locale_fallback(mut self, locale: &str) -> Self {
+        self.locale_fallback = Some(locale.to_owned());
+        self
+    }
+
+    /// Configure what happens when a dataset comes back valid but empty, e.x.
+    /// `{"data":{}}` while the CDN is partway through deploying a patch.
+    /// Defaults to [EmptyDatasets::Allow], since some datasets can
+    /// legitimately be small or empty. Checking a dataset means parsing it
+    /// twice, once to inspect it and once into the model.
+    pub fn empty_datasets(mut self, policy: EmptyDatasets) -> Self {
+        self.empty_datasets = policy;
+        self
+    }
+
+    /// Verifies downloaded data against the `Content-MD5` header sent with
+    /// it, if any, before it is cached. A mismatch, e.g. from a truncated
+    /// download, fails with [ClientError::ChecksumMismatch] and nothing is
+    /// cached. Responses without the header are accepted as-is. Disabled by
+    /// default, and has no effect if a custom agent is specified.
+    pub fn verify_checksums(mut self, verify: bool) -> Self {
+        self.verify_checksums = verify;
+        self
+    }
+
+    /// Configures the maximum number of idle connections kept open for reuse
+    /// per host. By default `reqwest` does not limit this. Has no effect if a
+    /// custom agent is specified.
+    pub fn max_idle_connections_per_host(mut self, max: usize) -> Self {
+        self.max_idle_connections_per_host = Some(max);
+        self
+    }
+
+    /// Configures how long an idle connection is kept alive before it is
+    /// closed. Defaults to `reqwest`'s default of 90 seconds. Has no effect if
+    /// a custom agent is specified.
+    pub fn max_idle_age(mut self, age: Duration) -> Self {
+        self.max_idle_age = Some(age);
+        self
+    }
+
+    /// Configures how long a whole request, including reading the response,
+    /// may take before it fails with [ClientError::Timeout]. By default
+    /// `reqwest` sets no overall limit. Has no effect if a custom agent is
+    /// specified.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Configures how long a cached version list is reused before it's
+    /// fetched again. Defaults to five minutes, so frequently built clients
+    /// don't hit the network every time. If fetching fails, a cached list of
+    /// any age is used instead, so a client can be built offline once the
+    /// list has been cached. Has no effect without a cache directory.
+    pub fn versions_ttl(mut self, ttl: Duration) -> Self {
+        self.versions_ttl = ttl;
+        self
+    }
+
+    /// Configures whether cached responses are revalidated with the server
+    /// once they're stale according to its cache headers, using
+    /// `If-Modified-Since` or `If-None-Match`. On `304 Not Modified`, or if
+    /// the server can't be reached, the cached body is used. Off by default,
+    /// as ddragon's versioned files never change, but helps with mirrors that
+    /// update files in place. Has no effect if a custom agent is specified.
+    pub fn revalidate(mut self, revalidate: bool) -> Self {
+        self.revalidate = revalidate;
+        self
+    }
+
+    /// Configures how many requests bulk operations, such as fetching every
+    /// champion individually, keep in flight at once. Defaults to 8, which is
+    /// gentle enough on the CDN to avoid being rate limited. Values below 1
+    /// are treated as 1.
+    pub fn concurrency(mut self, limit: usize) -> Self {
+        self.concurrency = limit.max(1);
+        self
+    }
+
+    /// Configures the TCP keep-alive interval for open connections. This is
+    /// disabled by default. Has no effect if a custom agent is specified.
+    pub fn tcp_keepalive(mut self, interval: Duration) -> Self {
+        self.tcp_keepalive = Some(interval);
+        self
+    }
+
     #[cfg(test)]
     fn server(mut self, server: &str) -> Self {
         self.server = server.to_owned();
@@ -141,53 +375,98 @@ impl AsyncClientBuilder {
     /// # Notes
     ///
     /// - If a custom [Client] is specified, not specifying a cache directory will
-    /// result in no content being cached.
+    ///   result in no content being cached.
     pub async fn build(self) -> Result<AsyncClient, ClientError> {
         let agent = match self.agent {
             Some(a) => a,
-            None => ClientAgent::Plain(Client::new()),
-        };
-
-        let base_url = Url::parse(&self.server)?;
-
-        let latest_version = if let Some(version) = self.version {
-            version
-        } else {
-            let version_list = match agent.clone() {
-                ClientAgent::Plain(a) => {
-                    a.get(base_url.join("/api/versions.json")?.as_str())
-                        .send()
-                        .await?
-                        .json::<Vec<String>>()
-                        .await?
+            None => {
+                let mut builder = Client::builder();
+                if let Some(max) = self.max_idle_connections_per_host {
+                    builder = builder.pool_max_idle_per_host(max);
                 }
-                ClientAgent::Middleware(a) => {
-                    a.get(base_url.join("/api/versions.json")?.as_str())
-                        .send()
-                        .await?
-                        .json::<Vec<String>>()
-                        .await?
+                if let Some(age) = self.max_idle_age {
+                    builder = builder.pool_idle_timeout(age);
                 }
-            };
+                if let Some(timeout) = self.timeout {
+                    builder = builder.timeout(timeout);
+                }
+                if let Some(interval) = self.tcp_keepalive {
+                    builder = builder.tcp_keepalive(interval);
+                }
+                ClientAgent::Plain(builder.build()?)
+            }
+        };
 
-            version_list.get(0).ok_or(ClientError::NoLatestVersion)?.to_owned()
+        let base_url = match self.local_dir {
+            Some(dir) => local_dir_url(&dir)?,
+            None => Url::parse(&self.server)?,
         };
 
-        let middleware_agent = match agent {
-            ClientAgent::Plain(plain_agent) => match self.cache {
-                Some(cache_dir) => MiddlewareClientBuilder::new(plain_agent)
-                    .with(Cache(HttpCache {
-                        mode: CacheMode::ForceCache,
+        // Version lists are fetched without the HTTP cache, which would keep
+        // them forever, and are instead cached for `versions_ttl`.
+        let (middleware_agent, uncached_agent, cache_dir) = match agent {
+            ClientAgent::Plain(plain_agent) => {
+                let uncached_agent = MiddlewareClientBuilder::new(plain_agent.clone()).build();
+                let mut middleware_agent = MiddlewareClientBuilder::new(plain_agent);
+                if let Some(cache_dir) = &self.cache {
+                    middleware_agent = middleware_agent.with(Cache(HttpCache {
+                        mode: if self.revalidate {
+                            CacheMode::Default
+                        } else {
+                            CacheMode::ForceCache
+                        },
                         manager: CACacheManager { path: cache_dir.into() },
                         options: HttpCacheOptions::default(),
-                    }))
-                    .build(),
-                None => MiddlewareClientBuilder::new(plain_agent).build(),
-            },
-            ClientAgent::Middleware(middleware_agent) => middleware_agent,
+                    }));
+                }
+                // Added after the cache so that it sits closer to the network,
+                // and a mismatch fails before the response is cached.
+                if self.verify_checksums {
+                    middleware_agent = middleware_agent.with(AsyncChecksumMiddleware);
+                }
+                let middleware_agent = middleware_agent.build();
+                (middleware_agent, uncached_agent, self.cache)
+            }
+            ClientAgent::Middleware(middleware_agent) => {
+                (middleware_agent.clone(), middleware_agent, None)
+            }
+        };
+
+        let latest_version = if let Some(version) = self.version {
+            version
+        } else {
+            get_cached_versions(
+                &uncached_agent,
+                cache_dir.as_deref(),
+                &base_url,
+                self.local_preprocessor.as_ref(),
+                self.versions_ttl,
+            )
+            .await?
+            .first()
+            .ok_or(ClientError::NoLatestVersion)?
+            .to_owned()
         };
+        debug!("using ddragon version {} from {}", latest_version, base_url);
 
-        Ok(AsyncClient { agent: middleware_agent, version: latest_version, base_url })
+        let data_url = data_url_for(&base_url, &latest_version, &self.locale)?;
+
+        Ok(AsyncClient {
+            agent: middleware_agent,
+            uncached_agent,
+            cache_dir,
+            version: latest_version,
+            locale: self.locale,
+            locale_fallback: self.locale_fallback,
+            empty_datasets: self.empty_datasets,
+            concurrency: self.concurrency,
+            versions_ttl: self.versions_ttl,
+            local_preprocessor: self.local_preprocessor,
+            base_url,
+            data_url,
+            #[cfg(feature = "image")]
+            sprites: Default::default(),
+        })
     }
 }
 
@@ -201,9 +480,197 @@ impl Default for AsyncClientBuilder {
 /// Provides access to the ddragon API.
 pub struct AsyncClient {
     agent: ClientWithMiddleware,
+    uncached_agent: ClientWithMiddleware,
+    cache_dir: Option<String>,
     /// The current version of the API data reported back to us from the API.
     pub version: String,
+    locale: String,
+    locale_fallback: Option<String>,
+    empty_datasets: EmptyDatasets,
+    concurrency: usize,
+    versions_ttl: Duration,
+    local_preprocessor: Option<LocalPreprocessor>,
     base_url: Url,
+    data_url: Url,
+    #[cfg(feature = "image")]
+    sprites: Arc<Mutex<HashMap<String, DynamicImage>>>,
+}
+
+fn data_url_for(base_url: &Url, version: &str, locale: &str) -> Result<Url, url::ParseError> {
+    base_url.join(&format!("cdn/{}/data/{}/", version, locale))
+}
+
+/// Resolves a challenge icon path, which may already be a full URL, against
+/// the unversioned `cdn/img/` directory.
+fn challenge_icon_url(base_url: &Url, path: &str) -> Result<Url, url::ParseError> {
+    if let Ok(url) = Url::parse(path) {
+        return Ok(url);
+    }
+    let path = path.trim_start_matches('/');
+    base_url.join(&format!("cdn/img/{}", path.strip_prefix("cdn/img/").unwrap_or(path)))
+}
+
+fn local_dir_url(dir: &str) -> Result<Url, ClientError> {
+    let path = std::env::current_dir().map_err(ClientError::LocalFile)?.join(dir);
+    Url::from_directory_path(&path).map_err(|_| {
+        ClientError::LocalFile(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("{} is not a valid directory", path.display()),
+        ))
+    })
+}
+
+fn local_file_path(url: &Url) -> Result<PathBuf, ClientError> {
+    url.to_file_path().map_err(|_| {
+        ClientError::LocalFile(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("{url} is not a valid file path"),
+        ))
+    })
+}
+
+async fn read_local_file(url: &Url) -> Result<Vec<u8>, ClientError> {
+    // Read through tokio so large mirror files don't block the executor.
+    tokio::fs::read(local_file_path(url)?).await.map_err(ClientError::LocalFile)
+}
+
+/// Transforms the raw bytes of a local mirror file before they are parsed.
+type LocalPreprocessor = Arc<dyn Fn(Vec<u8>) -> Vec<u8> + Send + Sync>;
+
+async fn read_local_data(
+    url: &Url,
+    preprocess: Option<&LocalPreprocessor>,
+) -> Result<Vec<u8>, ClientError> {
+    let bytes = read_local_file(url).await?;
+    Ok(match preprocess {
+        Some(preprocess) => preprocess(bytes),
+        None => bytes,
+    })
+}
+
+/// Fetches the raw body at `url`, from the local mirror or over the network.
+/// Every JSON and image fetch goes through here, so they all share the same
+/// request behaviour.
+async fn get_bytes(
+    agent: &ClientWithMiddleware,
+    url: &Url,
+    locale: Option<&str>,
+    preprocess: Option<&LocalPreprocessor>,
+) -> Result<Vec<u8>, ClientError> {
+    fetch_bytes(agent, url, locale, preprocess).await.map(|(body, _)| body)
+}
+
+/// Like [get_bytes], but also reports whether the body came from the cache.
+async fn fetch_bytes(
+    agent: &ClientWithMiddleware,
+    url: &Url,
+    locale: Option<&str>,
+    preprocess: Option<&LocalPreprocessor>,
+) -> Result<(Vec<u8>, bool), ClientError> {
+    if url.scheme() == "file" {
+        debug!("reading {} from the local mirror", url);
+        return Ok((read_local_data(url, preprocess).await?, false));
+    }
+
+    let mut request = agent.get(url.as_str());
+    if let Some(locale) = locale {
+        request = request.header("Accept-Language", accept_language(locale));
+    }
+    let response = request.send().await?.error_for_status()?;
+    // Set by `http-cache` to `HIT` or `MISS` when the cache is enabled.
+    let from_cache = response
+        .headers()
+        .get(CACHE_STATUS_HEADER)
+        .is_some_and(|status| status.as_bytes() == b"HIT");
+    let body = response.bytes().await?.to_vec();
+    debug!("fetched {} ({} bytes, from cache: {})", url, body.len(), from_cache);
+    Ok((body, from_cache))
+}
+
+async fn get_json<T: DeserializeOwned>(
+    agent: &ClientWithMiddleware,
+    url: &Url,
+    locale: Option<&str>,
+    preprocess: Option<&LocalPreprocessor>,
+) -> Result<T, ClientError> {
+    let body = get_bytes(agent, url, locale, preprocess).await?;
+    serde_json::from_slice(&body).map_err(|e| ClientError::json(e, &body))
+}
+
+/// Fetches the version list, reporting anything that isn't a list of versions
+/// (e.g. an error page) as [ClientError::UnexpectedVersions].
+async fn get_versions(
+    agent: &ClientWithMiddleware,
+    base_url: &Url,
+    preprocess: Option<&LocalPreprocessor>,
+) -> Result<Vec<String>, ClientError> {
+    let versions_url = base_url.join("api/versions.json")?;
+    let (status, body) = if versions_url.scheme() == "file" {
+        (None, read_local_data(&versions_url, preprocess).await?)
+    } else {
+        let response = agent.get(versions_url.as_str()).send().await?;
+        let status = response.status();
+        let body = response.bytes().await?.to_vec();
+        if !status.is_success() {
+            return Err(ClientError::unexpected_versions(Some(status.as_u16()), &body));
+        }
+        (Some(status.as_u16()), body)
+    };
+
+    serde_json::from_slice(&body).map_err(|_| ClientError::unexpected_versions(status, &body))
+}
+
+/// Fetches the version list through the cache in `cache_dir`, reusing a
+/// cached list younger than `ttl` and falling back to a cached list of any age
+/// if the fetch fails.
+async fn get_cached_versions(
+    agent: &ClientWithMiddleware,
+    cache_dir: Option<&str>,
+    base_url: &Url,
+    preprocess: Option<&LocalPreprocessor>,
+    ttl: Duration,
+) -> Result<Vec<String>, ClientError> {
+    let cache_dir = match cache_dir {
+        Some(cache_dir) if base_url.scheme() != "file" => cache_dir,
+        _ => return get_versions(agent, base_url, preprocess).await,
+    };
+
+    // Kept apart from the `GET:` keys `http-cache` stores responses under.
+    let key = format!("versions:{}", base_url.join("api/versions.json")?);
+    let cached: Option<Vec<String>> = cacache::read(cache_dir, &key)
+        .await
+        .ok()
+        .and_then(|body| serde_json::from_slice(&body).ok());
+    let is_fresh = cacache::metadata(cache_dir, &key)
+        .await
+        .ok()
+        .flatten()
+        .and_then(|metadata| u64::try_from(metadata.time).ok())
+        .and_then(|millis| UNIX_EPOCH.checked_add(Duration::from_millis(millis)))
+        .and_then(|written_at| written_at.elapsed().ok())
+        .is_some_and(|age| age < ttl);
+    if let (Some(versions), true) = (&cached, is_fresh) {
+        trace!("cache hit for {}", key);
+        return Ok(versions.clone());
+    }
+
+    match get_versions(agent, base_url, preprocess).await {
+        Ok(versions) => {
+            if !versions.is_empty() {
+                if let Ok(body) = serde_json::to_vec(&versions) {
+                    let _ = cacache::write(cache_dir, &key, body).await;
+                }
+            }
+            Ok(versions)
+        }
+        Err(e) => match cached {
+            Some(versions) => {
+                debug!("using cached versions after failing to fetch them: {}", e);
+                Ok(versions)
+            }
+            None => Err(e),
+        },
+    }
 }
 
 macro_rules! create_endpoint {
@@ -239,278 +706,1914 @@ impl AsyncClient {
         AsyncClientBuilder::new().cache(cache_dir).build().await
     }
 
-    fn get_data_url(&self) -> Result<Url, url::ParseError> {
-        self.base_url.join(&format!("/cdn/{}/data/en_US/", &self.version))
+    /// Creates a new client configured from the environment. See
+    /// [AsyncClientBuilder::from_env] for the variables that are read.
+    ///
+    /// ```no_run
+    /// # tokio_test::block_on(async {
+    /// use ddragon::AsyncClient;
+    ///
+    /// let api = AsyncClient::from_env().await.unwrap();
+    /// # })
+    /// ```
+    pub async fn from_env() -> Result<Self, ClientError> {
+        AsyncClientBuilder::from_env().build().await
     }
 
-    async fn get_data<T: DeserializeOwned>(&self, endpoint: &str) -> Result<T, ClientError> {
-        let joined_url = self.get_data_url()?.join(endpoint)?;
-        let request_url = joined_url.as_str();
+    /// Creates a new client from an existing [Client] and an already known
+    /// version, e.x. one saved from a previous run. Unlike the other
+    /// constructors this makes no network requests, as the version list is
+    /// never fetched.
+    ///
+    /// ```no_run
+    /// # tokio_test::block_on(async {
+    /// use ddragon::AsyncClient;
+    ///
+    /// let api = AsyncClient::with_existing_version(reqwest::Client::new(), "14.1.1")
+    ///     .await
+    ///     .unwrap();
+    /// # })
+    /// ```
+    pub async fn with_existing_version(agent: Client, version: &str) -> Result<Self, ClientError> {
+        AsyncClientBuilder::new().agent(agent).version(version).build().await
+    }
 
-        self.agent.get(request_url).send().await?.json::<T>().await.map_err(|e| e.into())
+    /// Creates a new client that fetches from `base_url` instead of ddragon,
+    /// pinned to `version` so no version list is requested. This is mainly
+    /// for pointing a client at a local fixture or mock server in tests.
+    ///
+    /// ```no_run
+    /// # tokio_test::block_on(async {
+    /// use ddragon::AsyncClient;
+    ///
+    /// let api = AsyncClient::with_base_url_and_version("http://127.0.0.1:1234", "14.1.1")
+    ///     .await
+    ///     .unwrap();
+    /// # })
+    /// ```
+    pub async fn with_base_url_and_version(
+        base_url: &str,
+        version: &str,
+    ) -> Result<Self, ClientError> {
+        AsyncClientBuilder { server: base_url.to_owned(), ..AsyncClientBuilder::new() }
+            .version(version)
+            .build()
+            .await
     }
 
-    create_endpoint!(challenges, "challenge", "challenges", Challenges);
-    create_endpoint!(champions, "champion", "champion", Champions);
-    create_endpoint!(champions_full, "complete champion", "championFull", ChampionsFull);
-    create_endpoint!(items, "item", "item", Items);
-    create_endpoint!(maps, "map", "map", Maps);
-    create_endpoint!(mission_assets, "mission asset", "mission-assets", MissionAssets);
-    create_endpoint!(profile_icons, "profile icon", "profileicon", ProfileIcons);
-    create_endpoint!(runes, "rune", "runesReforged", Runes);
-    create_endpoint!(spell_buffs, "spell buff", "spellbuffs", SpellBuffs);
-    create_endpoint!(summoner_spells, "summoner_spells", "summoner", SummonerSpells);
-    create_endpoint!(translations, "translation", "language", Translations);
-    create_endpoint!(tft_arenas, "TFT arena", "tft-arena", Arenas);
-    create_endpoint!(tft_augments, "TFT augment", "tft-augments", Augments);
-    create_endpoint!(tft_champions, "TFT champion", "tft-champion", tft::Champions);
-    create_endpoint!(tft_hero_augments, "TFT hero augment", "tft-hero-augments", HeroAugments);
-    create_endpoint!(tft_items, "TFT item", "tft-item", tft::Items);
-    create_endpoint!(tft_queues, "TFT queue", "tft-queues", Queues);
-    create_endpoint!(tft_regalia, "TFT regalia", "tft-regalia", Regalia);
-    create_endpoint!(tft_tacticians, "TFT tactician", "tft-tactician", Tacticians);
-    create_endpoint!(tft_traits, "TFT trait", "tft-trait", Traits);
+    /// Fetches every ddragon version, newest first, as listed in
+    /// `api/versions.json`. A cached list is reused for the builder's
+    /// [versions_ttl](AsyncClientBuilder::versions_ttl), and used regardless
+    /// of age if fetching fails.
+    ///
+    /// ```no_run
+    /// # tokio_test::block_on(async {
+    /// use ddragon::AsyncClient;
+    ///
+    /// let api = AsyncClient::new("./cache").await.unwrap();
+    /// let versions = api.versions().await.unwrap();
+    /// # })
+    /// ```
+    pub async fn versions(&self) -> Result<Vec<String>, ClientError> {
+        get_cached_versions(
+            &self.uncached_agent,
+            self.cache_dir.as_deref(),
+            &self.base_url,
+            self.local_preprocessor.as_ref(),
+            self.versions_ttl,
+        )
+        .await
+    }
 
-    /// Returns data for a single champion. The champion's name or numeric key
-    /// should not be used here -- this should be the key property on the
-    /// Champion struct. This is usually the name, but differs in a bunch of
-    /// cases (e.x. Wukong's key is MonkeyKing).
+    /// Checks that ddragon is reachable and reporting at least one version,
+    /// which makes this suitable for readiness probes. Only the small version
+    /// list is requested, and it is never served from the cache.
     ///
     /// ```no_run
     /// # tokio_test::block_on(async {
     /// use ddragon::AsyncClient;
     ///
     /// let api = AsyncClient::new("./cache").await.unwrap();
-    /// let wukong = api.champion("MonkeyKing").await.unwrap();
+    /// if api.health_check().await.is_err() {
+    ///     eprintln!("ddragon is unreachable");
+    /// }
     /// # })
     /// ```
-    pub async fn champion(&self, key: &str) -> Result<Champion, ClientError> {
-        self.get_data::<ChampionWrapper>(&format!("./champion/{key}.json"))
+    pub async fn health_check(&self) -> Result<(), ClientError> {
+        if get_versions(&self.uncached_agent, &self.base_url, self.local_preprocessor.as_ref())
             .await?
-            .data
-            .get(key)
-            .cloned()
-            .ok_or(ClientError::NoChampionData)
+            .is_empty()
+        {
+            return Err(ClientError::NoLatestVersion);
+        }
+        Ok(())
     }
 
-    #[cfg(feature = "image")]
-    async fn get_image(&self, path: Url) -> Result<DynamicImage, ClientError> {
-        let response = self
-            .agent
-            .get(path.as_str())
-            .send()
-            .await
-            .map_err(std::convert::Into::<ClientError>::into)?;
-
-        load_from_memory(&response.bytes().await?).map_err(|e| e.into())
+    /// Fetches the versions a region is currently serving, e.x. `na` or
+    /// `euw`. Realms are never served from the cache.
+    ///
+    /// ```no_run
+    /// # tokio_test::block_on(async {
+    /// use ddragon::AsyncClient;
+    ///
+    /// let api = AsyncClient::new("./cache").await.unwrap();
+    /// let realm = api.realm("euw").await.unwrap();
+    /// println!("EUW serves {:?}", realm.dd_version());
+    /// # })
+    /// ```
+    pub async fn realm(&self, region: &str) -> Result<Realm, ClientError> {
+        let realm_url = self.base_url.join(&format!("realms/{}.json", region))?;
+        get_json(&self.uncached_agent, &realm_url, None, self.local_preprocessor.as_ref()).await
     }
 
-    /// Returns an [image::DynamicImage].
+    /// Switches this client to the CDN and Data Dragon version a region is
+    /// serving, so data and image URLs match what players in that region
+    /// see. The realm's `cdn` is expected to end in `/cdn`, as Riot's do.
     ///
     /// ```no_run
     /// # tokio_test::block_on(async {
     /// use ddragon::AsyncClient;
     ///
     /// let api = AsyncClient::new("./cache").await.unwrap();
-    /// let champion = api.champion("MonkeyKing").await.unwrap();
-    /// let image = api.image_of(&champion).await.unwrap();
+    /// let realm = api.realm("euw").await.unwrap();
+    /// let api = api.with_realm(&realm).unwrap();
     /// # })
     /// ```
-    #[cfg(feature = "image")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "image")))]
-    pub async fn image_of<T: HasImage>(&self, item: &T) -> Result<DynamicImage, ClientError> {
-        self.get_image(self.base_url.join(&format!(
-            "/cdn/{}/img/{}",
-            &self.version,
-            item.image_path()
-        ))?)
-        .await
+    pub fn with_realm(mut self, realm: &Realm) -> Result<Self, ClientError> {
+        let cdn = realm.cdn.trim_end_matches('/');
+        self.base_url = Url::parse(&format!("{}/", cdn.strip_suffix("/cdn").unwrap_or(cdn)))?;
+        self.version = realm.dd_version().unwrap_or(&realm.v).to_owned();
+        self.data_url = data_url_for(&self.base_url, &self.version, &self.locale)?;
+        Ok(self)
     }
 
-    /// Returns an [image::DynamicImage].
-    ///
-    /// Keep in mind that this is a spritesheet image. You will have to cut out
-    /// the appropriate piece using the information on the [Image](crate::models::shared::Image).
+    /// Returns whether a dataset file, e.x. `item.json`, is cached for the
+    /// current version, without reading or parsing it. Always `false` when no
+    /// cache directory is configured, including when a custom client is used.
     ///
     /// ```no_run
     /// # tokio_test::block_on(async {
     /// use ddragon::AsyncClient;
     ///
     /// let api = AsyncClient::new("./cache").await.unwrap();
-    /// let champion = api.champion("MonkeyKing").await.unwrap();
-    /// let sprite = api.sprite_of(&champion).await.unwrap();
+    /// let downloaded = api.is_cached("championFull.json").await;
     /// # })
     /// ```
-    #[cfg(feature = "image")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "image")))]
-    pub async fn sprite_of<T: HasImage>(&self, item: &T) -> Result<DynamicImage, ClientError> {
-        self.get_image(self.base_url.join(&format!(
-            "/cdn/{}/img/{}",
-            &self.version,
-            item.sprite_path()
-        ))?)
-        .await
+    pub async fn is_cached(&self, endpoint: &str) -> bool {
+        let (Some(cache_dir), Ok(url)) =
+            (&self.cache_dir, self.get_data_url().and_then(|url| url.join(endpoint)))
+        else {
+            return false;
+        };
+        // Matches the key format used by `http-cache`.
+        matches!(cacache::metadata(cache_dir, format!("GET:{url}")).await, Ok(Some(_)))
     }
-}
 
-#[cfg(test)]
-mod test {
-    use super::*;
-    use mockito::{Server, ServerGuard};
+    /// Fetches and caches dataset files, e.x. `item.json`, for the current
+    /// version up front, so later requests for them are served from the cache.
+    /// Files that are already [cached](AsyncClient::is_cached) are skipped, and
+    /// up to the configured [concurrency](AsyncClientBuilder::concurrency)
+    /// requests are in flight at once. Every endpoint is attempted even if some
+    /// fail; failures are reported together in [ClientError::Prewarm].
+    ///
+    /// ```no_run
+    /// # tokio_test::block_on(async {
+    /// use ddragon::AsyncClient;
+    ///
+    /// let api = AsyncClient::new("./cache").await.unwrap();
+    /// api.prewarm(&["champion.json", "item.json", "summoner.json"]).await.unwrap();
+    /// # })
+    /// ```
+    pub async fn prewarm(&self, endpoints: &[&str]) -> Result<(), ClientError> {
+        let data_url = self.get_data_url()?;
+        let mut pending = Vec::new();
+        for endpoint in endpoints {
+            if !self.is_cached(endpoint).await {
+                pending.push(*endpoint);
+            }
+        }
 
-    async fn create_mock_client() -> (ServerGuard, String, AsyncClient) {
-        let server = Server::new_async().await;
-        let url = server.url();
-        (
-            server,
-            url.clone(),
-            AsyncClient {
-                agent: MiddlewareClientBuilder::new(Client::new()).build(),
-                version: "0.0.0".to_owned(),
-                base_url: Url::parse(&url).unwrap(),
-            },
+        let mut results = stream::iter(pending)
+            .map(|endpoint| {
+                let data_url = &data_url;
+                async move {
+                    let result = match data_url.join(endpoint) {
+                        Ok(url) => get_bytes(
+                            &self.agent,
+                            &url,
+                            Some(&self.locale),
+                            self.local_preprocessor.as_ref(),
+                        )
+                        .await
+                        .map(|_| ()),
+                        Err(e) => Err(e.into()),
+                    };
+                    (endpoint, result)
+                }
+            })
+            .buffered(self.concurrency);
+        let mut failures = Vec::new();
+        while let Some((endpoint, result)) = results.next().await {
+            if let Err(error) = result {
+                failures.push((endpoint.to_owned(), error));
+            }
+        }
+
+        if failures.is_empty() {
+            Ok(())
+        } else {
+            Err(ClientError::Prewarm { failures })
+        }
+    }
+
+    /// Estimates how many bytes fetching every dataset for the current version
+    /// and locale would download, by summing the `Content-Length` of a `HEAD`
+    /// request for each file. Up to the configured
+    /// [concurrency](AsyncClientBuilder::concurrency) requests are in flight
+    /// at once. Files the server doesn't report a length for count as zero,
+    /// and images aren't included.
+    ///
+    /// ```no_run
+    /// # tokio_test::block_on(async {
+    /// use ddragon::AsyncClient;
+    ///
+    /// let api = AsyncClient::new("./cache").await.unwrap();
+    /// let size = api.estimate_patch_size().await.unwrap();
+    /// println!("This will download ~{} MB", size / 1_000_000);
+    /// # })
+    /// ```
+    pub async fn estimate_patch_size(&self) -> Result<u64, ClientError> {
+        let data_url = self.get_data_url()?;
+        let urls =
+            DATA_FILES.iter().map(|file| data_url.join(file)).collect::<Result<Vec<_>, _>>()?;
+
+        let mut sizes = stream::iter(urls)
+            .map(|url| async move { self.content_length(&url).await })
+            .buffer_unordered(self.concurrency);
+        let mut total = 0;
+        while let Some(size) = sizes.next().await {
+            total += size?;
+        }
+        Ok(total)
+    }
+
+    async fn content_length(&self, url: &Url) -> Result<u64, ClientError> {
+        if url.scheme() == "file" {
+            let metadata = tokio::fs::metadata(local_file_path(url)?).await;
+            return Ok(metadata.map_err(ClientError::LocalFile)?.len());
+        }
+
+        // `HEAD` responses have no body worth caching, so skip the cache.
+        let response = self
+            .uncached_agent
+            .head(url.as_str())
+            .header("Accept-Language", accept_language(&self.locale))
+            .send()
+            .await?
+            .error_for_status()?;
+        Ok(response
+            .headers()
+            .get("Content-Length")
+            .and_then(|length| length.to_str().ok()?.parse().ok())
+            .unwrap_or(0))
+    }
+
+    /// Returns how long ago a dataset file, e.x. `item.json`, was cached for
+    /// the current version. `None` when the file isn't cached or no cache
+    /// directory is configured, including when a custom client is used.
+    ///
+    /// ```no_run
+    /// # tokio_test::block_on(async {
+    /// use ddragon::AsyncClient;
+    ///
+    /// let api = AsyncClient::new("./cache").await.unwrap();
+    /// if let Some(age) = api.cache_age("item.json").await {
+    ///     println!("Item data is {} days old", age.as_secs() / 86400);
+    /// }
+    /// # })
+    /// ```
+    pub async fn cache_age(&self, endpoint: &str) -> Option<Duration> {
+        let url = self.get_data_url().and_then(|url| url.join(endpoint)).ok()?;
+        // Matches the key format used by `http-cache`.
+        let metadata =
+            cacache::metadata(self.cache_dir.as_ref()?, format!("GET:{url}")).await.ok()??;
+        let millis = u64::try_from(metadata.time).ok()?;
+        let written_at = UNIX_EPOCH.checked_add(Duration::from_millis(millis))?;
+        Some(SystemTime::now().duration_since(written_at).unwrap_or_default())
+    }
+
+    fn get_data_url(&self) -> Result<Cow<'_, Url>, url::ParseError> {
+        // The version can be changed after the client is built, so the cached
+        // URL is only reused while it still points at the current version.
+        if self.data_url.path_segments().and_then(|mut segments| segments.nth_back(3))
+            == Some(self.version.as_str())
+        {
+            Ok(Cow::Borrowed(&self.data_url))
+        } else {
+            data_url_for(&self.base_url, &self.version, &self.locale).map(Cow::Owned)
+        }
+    }
+
+    async fn get_data<T: DeserializeOwned>(&self, endpoint: &str) -> Result<T, ClientError> {
+        let joined_url = self.get_data_url()?.join(endpoint)?;
+        self.get_localized_json(&joined_url, endpoint, &self.version, &self.locale).await
+    }
+
+    async fn get_localized_json<T: DeserializeOwned>(
+        &self,
+        url: &Url,
+        endpoint: &str,
+        version: &str,
+        locale: &str,
+    ) -> Result<T, ClientError> {
+        let body =
+            get_bytes(&self.agent, url, Some(locale), self.local_preprocessor.as_ref()).await?;
+        self.check_data(&body, endpoint, version, locale).await
+    }
+
+    /// Parses a dataset that was downloaded some other way with the same
+    /// checks and locale fallback as
+    /// [get_localized_json](Self::get_localized_json).
+    async fn parse_checked<T: DeserializeOwned>(
+        &self,
+        body: &[u8],
+        endpoint: &str,
+    ) -> Result<T, ClientError> {
+        self.check_data(body, endpoint, &self.version, &self.locale).await
+    }
+
+    fn locale_fallback_for(&self, locale: &str) -> Option<&str> {
+        self.locale_fallback.as_deref().filter(|fallback| *fallback != locale)
+    }
+
+    async fn check_data<T: DeserializeOwned>(
+        &self,
+        body: &[u8],
+        endpoint: &str,
+        version: &str,
+        locale: &str,
+    ) -> Result<T, ClientError> {
+        let fallback = self.locale_fallback_for(locale);
+        // Parsing into a `Value` first is slower, so it's only done when the
+        // data has to be looked at before it's parsed into a model.
+        if fallback.is_none() && self.empty_datasets == EmptyDatasets::Allow {
+            return serde_json::from_slice(body).map_err(|e| ClientError::json(e, body));
+        }
+
+        let mut data: Value =
+            serde_json::from_slice(body).map_err(|e| ClientError::json(e, body))?;
+        check_empty_dataset(&data, endpoint, self.empty_datasets)?;
+        if let Some(fallback) = fallback.filter(|_| has_missing_names(&data)) {
+            let fallback_url = data_url_for(&self.base_url, version, fallback)?.join(endpoint)?;
+            let fallback_data: Value = get_json(
+                &self.agent,
+                &fallback_url,
+                Some(fallback),
+                self.local_preprocessor.as_ref(),
+            )
+            .await?;
+            fill_missing_names(&mut data, &fallback_data);
+        }
+        T::deserialize(data).map_err(|e| {
+            // A `Value` has no positions, so the body is parsed again to find
+            // where it went wrong. Filling in names doesn't change the shape
+            // of the data, so this is expected to fail the same way.
+            match serde_json::from_slice::<T>(body) {
+                Err(located) => ClientError::json(located, body),
+                Ok(_) => ClientError::json(e, &[]),
+            }
+        })
+    }
+
+    async fn get_data_with_progress<T: DeserializeOwned>(
+        &self,
+        endpoint: &str,
+        mut progress: impl FnMut(u64, Option<u64>),
+    ) -> Result<T, ClientError> {
+        let joined_url = self.get_data_url()?.join(endpoint)?;
+        if joined_url.scheme() == "file" {
+            let bytes = read_local_data(&joined_url, self.local_preprocessor.as_ref()).await?;
+            progress(bytes.len() as u64, Some(bytes.len() as u64));
+            return self.parse_checked(&bytes, endpoint).await;
+        }
+
+        let mut response = self
+            .agent
+            .get(joined_url.as_str())
+            .header("Accept-Language", accept_language(&self.locale))
+            .send()
+            .await?
+            .error_for_status()?;
+        let total = response.content_length();
+        let mut bytes = Vec::with_capacity(total.unwrap_or(0) as usize);
+        while let Some(chunk) = response.chunk().await? {
+            bytes.extend_from_slice(&chunk);
+            progress(bytes.len() as u64, total);
+        }
+
+        self.parse_checked(&bytes, endpoint).await
+    }
+
+    /// Fetches any dataset file, e.x. `item.json`, with the locale and version
+    /// optionally overridden by `options`. Unset options fall back to the
+    /// client's own locale and version.
+    ///
+    /// ```no_run
+    /// # tokio_test::block_on(async {
+    /// use ddragon::{models::Items, AsyncClient, FetchOptions};
+    ///
+    /// let api = AsyncClient::new("./cache").await.unwrap();
+    /// let options = FetchOptions::new().locale("ko_KR").version("13.1.1");
+    /// let items: Items = api.get_data_with("item.json", &options).await.unwrap();
+    /// # })
+    /// ```
+    pub async fn get_data_with<T: DeserializeOwned>(
+        &self,
+        endpoint: &str,
+        options: &FetchOptions,
+    ) -> Result<T, ClientError> {
+        let version = options.version.as_deref().unwrap_or(&self.version);
+        let locale = options.locale.as_deref().unwrap_or(&self.locale);
+        let joined_url = data_url_for(&self.base_url, version, locale)?.join(endpoint)?;
+        self.get_localized_json(&joined_url, endpoint, version, locale).await
+    }
+
+    /// Fetches a dataset file, e.x. `item.json`, like
+    /// [AsyncClient::get_data_with] with no overrides, and reports how many
+    /// bytes were transferred, whether they came from the cache, and how long
+    /// the fetch took.
+    ///
+    /// The stats only cover `endpoint` itself, not any data fetched from the
+    /// [fallback locale](AsyncClientBuilder::locale_fallback) to fill in names.
+    ///
+    /// ```no_run
+    /// # tokio_test::block_on(async {
+    /// use ddragon::{models::Items, AsyncClient};
+    ///
+    /// let api = AsyncClient::new("./cache").await.unwrap();
+    /// let (items, stats) = api.fetch_with_stats::<Items>("item.json").await.unwrap();
+    /// println!("{} bytes in {:?}", stats.bytes, stats.duration);
+    /// # })
+    /// ```
+    pub async fn fetch_with_stats<T: DeserializeOwned>(
+        &self,
+        endpoint: &str,
+    ) -> Result<(T, FetchStats), ClientError> {
+        let joined_url = self.get_data_url()?.join(endpoint)?;
+        let started = Instant::now();
+        let (body, from_cache) = fetch_bytes(
+            &self.agent,
+            &joined_url,
+            Some(&self.locale),
+            self.local_preprocessor.as_ref(),
         )
+        .await?;
+        let stats =
+            FetchStats { bytes: body.len() as u64, from_cache, duration: started.elapsed() };
+        let data = self.parse_checked(&body, endpoint).await?;
+        Ok((data, stats))
+    }
+
+    /// Fetches any JSON file on the ddragon host, e.x. `cdn/languages.json`,
+    /// with `path` joined directly onto the base URL rather than the data
+    /// directory of a version and locale.
+    ///
+    /// ```no_run
+    /// # tokio_test::block_on(async {
+    /// use ddragon::AsyncClient;
+    ///
+    /// let api = AsyncClient::new("./cache").await.unwrap();
+    /// let languages = api.get_cdn("cdn/languages.json").await.unwrap();
+    /// # })
+    /// ```
+    pub async fn get_cdn(&self, path: &str) -> Result<Value, ClientError> {
+        let joined_url = self.base_url.join(path)?;
+        get_json(&self.agent, &joined_url, None, self.local_preprocessor.as_ref()).await
+    }
+
+    create_endpoint!(challenges, "challenge", "challenges", Challenges);
+    create_endpoint!(champions, "champion", "champion", Champions);
+    create_endpoint!(champions_full, "complete champion", "championFull", ChampionsFull);
+    create_endpoint!(items, "item", "item", Items);
+    create_endpoint!(maps, "map", "map", Maps);
+    create_endpoint!(mission_assets, "mission asset", "mission-assets", MissionAssets);
+    create_endpoint!(profile_icons, "profile icon", "profileicon", ProfileIcons);
+    create_endpoint!(runes, "rune", "runesReforged", Runes);
+    create_endpoint!(spell_buffs, "spell buff", "spellbuffs", SpellBuffs);
+    create_endpoint!(stickers, "sticker (emote)", "sticker", Stickers);
+    create_endpoint!(summoner_spells, "summoner_spells", "summoner", SummonerSpells);
+    create_endpoint!(translations, "translation", "language", Translations);
+    create_endpoint!(tft_arenas, "TFT arena", "tft-arena", Arenas);
+    create_endpoint!(tft_augments, "TFT augment", "tft-augments", Augments);
+    create_endpoint!(tft_champions, "TFT champion", "tft-champion", tft::Champions);
+    create_endpoint!(tft_hero_augments, "TFT hero augment", "tft-hero-augments", HeroAugments);
+    create_endpoint!(tft_items, "TFT item", "tft-item", tft::Items);
+    create_endpoint!(tft_queues, "TFT queue", "tft-queues", Queues);
+    create_endpoint!(tft_regalia, "TFT regalia", "tft-regalia", Regalia);
+    create_endpoint!(tft_tacticians, "TFT tactician", "tft-tactician", Tacticians);
+    create_endpoint!(tft_traits, "TFT trait", "tft-trait", Traits);
+
+    /// Returns complete champion data, like [champions_full](Self::champions_full),
+    /// while calling `progress` with the number of bytes read so far and the
+    /// total size of the response, if the server reported one. This dataset is
+    /// several megabytes, so this is useful for showing a progress bar.
+    ///
+    /// ```no_run
+    /// # tokio_test::block_on(async {
+    /// use ddragon::AsyncClient;
+    ///
+    /// let api = AsyncClient::new("./cache").await.unwrap();
+    /// let champions_full = api
+    ///     .champions_full_with_progress(|read, total| println!("{read}/{total:?} bytes"))
+    ///     .await
+    ///     .unwrap();
+    /// # })
+    /// ```
+    pub async fn champions_full_with_progress(
+        &self,
+        progress: impl FnMut(u64, Option<u64>),
+    ) -> Result<ChampionsFull, ClientError> {
+        self.get_data_with_progress("./championFull.json", progress).await
+    }
+
+    /// Returns data for a single champion. The champion's name or numeric key
+    /// should not be used here -- this should be the key property on the
+    /// Champion struct. This is usually the name, but differs in a bunch of
+    /// cases (e.x. Wukong's key is MonkeyKing).
+    ///
+    /// ```no_run
+    /// # tokio_test::block_on(async {
+    /// use ddragon::AsyncClient;
+    ///
+    /// let api = AsyncClient::new("./cache").await.unwrap();
+    /// let wukong = api.champion("MonkeyKing").await.unwrap();
+    /// # })
+    /// ```
+    pub async fn champion(&self, key: &str) -> Result<Champion, ClientError> {
+        self.get_data::<ChampionWrapper>(&format!("./champion/{key}.json"))
+            .await?
+            .data
+            .get(key)
+            .cloned()
+            .ok_or(ClientError::NoChampionData)
+    }
+
+    /// Returns a stream that fetches the full data for every champion. The
+    /// champion list is fetched up front, but each champion's data is only
+    /// requested as the stream is polled, with up to the configured
+    /// [concurrency](AsyncClientBuilder::concurrency) requests in flight at
+    /// once. Results are yielded in a stable order regardless.
+    ///
+    /// ```no_run
+    /// # tokio_test::block_on(async {
+    /// use ddragon::AsyncClient;
+    /// use futures_util::StreamExt;
+    ///
+    /// let api = AsyncClient::new("./cache").await.unwrap();
+    /// let mut champions = api.champions_full_individually().await.unwrap();
+    /// while let Some(champion) = champions.next().await {
+    ///     println!("{}", champion.unwrap().name);
+    /// }
+    /// # })
+    /// ```
+    pub async fn champions_full_individually(
+        &self,
+    ) -> Result<impl Stream<Item = Result<Champion, ClientError>> + '_, ClientError> {
+        let mut keys: Vec<String> = self.champions().await?.data.into_keys().collect();
+        keys.sort();
+
+        Ok(stream::iter(keys)
+            .map(move |key| async move { self.champion(&key).await })
+            .buffered(self.concurrency))
+    }
+
+    /// Returns the URL of a champion's tile art, the small square crop of
+    /// their default splash used in draft and loadout screens. Unlike most
+    /// images, tiles are not versioned. As with [champion](Self::champion),
+    /// this should be the champion's id (e.x. MonkeyKing for Wukong).
+    ///
+    /// ```no_run
+    /// # tokio_test::block_on(async {
+    /// use ddragon::AsyncClient;
+    ///
+    /// let api = AsyncClient::new("./cache").await.unwrap();
+    /// let url = api.champion_tile_url("MonkeyKing").unwrap();
+    /// # })
+    /// ```
+    pub fn champion_tile_url(&self, champion_id: &str) -> Result<Url, ClientError> {
+        Ok(self.base_url.join(&format!("cdn/img/champion/tiles/{champion_id}_0.jpg"))?)
+    }
+
+    /// Returns the URL of a champion's splash art for the given skin number,
+    /// without checking that the skin exists. As with
+    /// [champion_tile_url](Self::champion_tile_url), splashes are not
+    /// versioned and `champion_id` should be the champion's id.
+    ///
+    /// ```no_run
+    /// # tokio_test::block_on(async {
+    /// use ddragon::AsyncClient;
+    ///
+    /// let api = AsyncClient::new("./cache").await.unwrap();
+    /// let url = api.splash_art_url("MonkeyKing", 0).unwrap();
+    /// # })
+    /// ```
+    pub fn splash_art_url(&self, champion_id: &str, skin_num: i64) -> Result<Url, ClientError> {
+        Ok(self.base_url.join(&format!("cdn/img/champion/splash/{champion_id}_{skin_num}.jpg"))?)
+    }
+
+    /// Like [splash_art_url](Self::splash_art_url), but fetches the
+    /// champion's data first and returns `None` if it has no skin with that
+    /// number, so a missing splash is never requested.
+    ///
+    /// ```no_run
+    /// # tokio_test::block_on(async {
+    /// use ddragon::AsyncClient;
+    ///
+    /// let api = AsyncClient::new("./cache").await.unwrap();
+    /// if let Some(url) = api.splash_art_url_checked("MonkeyKing", 1).await.unwrap() {
+    ///     println!("{}", url);
+    /// }
+    /// # })
+    /// ```
+    pub async fn splash_art_url_checked(
+        &self,
+        champion_id: &str,
+        skin_num: i64,
+    ) -> Result<Option<Url>, ClientError> {
+        let champion = self.champion(champion_id).await?;
+        if !champion.skins.iter().any(|skin| skin.num == skin_num) {
+            return Ok(None);
+        }
+        self.splash_art_url(champion_id, skin_num).map(Some)
+    }
+
+    /// Returns the URL of each of a challenge's tier icons, lowest tier first.
+    /// Like champion tiles, challenge icons are not versioned.
+    ///
+    /// ```no_run
+    /// # tokio_test::block_on(async {
+    /// use ddragon::AsyncClient;
+    ///
+    /// let api = AsyncClient::new("./cache").await.unwrap();
+    /// let challenges = api.challenges().await.unwrap();
+    /// for (tier, url) in api.challenge_icon_urls(&challenges[0]).unwrap() {
+    ///     println!("{:?}: {}", tier, url);
+    /// }
+    /// # })
+    /// ```
+    pub fn challenge_icon_urls(
+        &self,
+        challenge: &Challenge,
+    ) -> Result<Vec<(ChallengeTier, Url)>, ClientError> {
+        challenge
+            .level_to_icon_path
+            .iter()
+            .map(|(tier, path)| Ok((tier, challenge_icon_url(&self.base_url, path)?)))
+            .collect()
+    }
+
+    /// Returns the URL of the full (non-sprite) image for anything that has
+    /// one, without downloading it.
+    ///
+    /// ```no_run
+    /// # tokio_test::block_on(async {
+    /// use ddragon::AsyncClient;
+    ///
+    /// let api = AsyncClient::new("./cache").await.unwrap();
+    /// let champion = api.champion("MonkeyKing").await.unwrap();
+    /// let url = api.image_url(&champion.summary()).unwrap();
+    /// # })
+    /// ```
+    pub fn image_url<T: HasImage>(&self, item: &T) -> Result<Url, ClientError> {
+        Ok(self.base_url.join(&format!("cdn/{}/img/{}", &self.version, item.image_path()))?)
+    }
+
+    /// Returns the URL of a champion's passive icon.
+    ///
+    /// ```no_run
+    /// # tokio_test::block_on(async {
+    /// use ddragon::AsyncClient;
+    ///
+    /// let api = AsyncClient::new("./cache").await.unwrap();
+    /// let champion = api.champion("MonkeyKing").await.unwrap();
+    /// let url = api.passive_image_url(&champion).unwrap();
+    /// # })
+    /// ```
+    pub fn passive_image_url(&self, champion: &Champion) -> Result<Url, ClientError> {
+        self.image_url(champion.passive())
+    }
+
+    /// Returns the URL of an ability's icon, under `img/spell/`.
+    ///
+    /// ```no_run
+    /// # tokio_test::block_on(async {
+    /// use ddragon::{models::champion::SpellSlot, AsyncClient};
+    ///
+    /// let api = AsyncClient::new("./cache").await.unwrap();
+    /// let champion = api.champion("MonkeyKing").await.unwrap();
+    /// let url = api.spell_icon_url(champion.spell(SpellSlot::Q).unwrap()).unwrap();
+    /// # })
+    /// ```
+    pub fn spell_icon_url(&self, spell: &Spell) -> Result<Url, ClientError> {
+        self.image_url(spell)
+    }
+
+    /// Returns the raw bytes of the full (non-sprite) image for anything that
+    /// has one, e.x. the PNG of an item's icon. The directory is taken from the
+    /// image's `group`, and the bytes are cached under the resolved URL just
+    /// like JSON data.
+    ///
+    /// ```no_run
+    /// # tokio_test::block_on(async {
+    /// use ddragon::AsyncClient;
+    ///
+    /// let api = AsyncClient::new("./cache").await.unwrap();
+    /// let champion = api.champion("MonkeyKing").await.unwrap();
+    /// let png = api.fetch_image(&champion.image).await.unwrap();
+    /// # })
+    /// ```
+    pub async fn fetch_image<T: HasImage>(&self, item: &T) -> Result<Vec<u8>, ClientError> {
+        get_bytes(&self.agent, &self.image_url(item)?, None, None).await
+    }
+
+    #[cfg(feature = "image")]
+    async fn get_image(&self, path: Url) -> Result<DynamicImage, ClientError> {
+        load_from_memory(&get_bytes(&self.agent, &path, None, None).await?).map_err(|e| e.into())
     }
 
-    mod create {
-        use super::*;
+    /// Returns an [image::DynamicImage].
+    ///
+    /// ```no_run
+    /// # tokio_test::block_on(async {
+    /// use ddragon::AsyncClient;
+    ///
+    /// let api = AsyncClient::new("./cache").await.unwrap();
+    /// let champion = api.champion("MonkeyKing").await.unwrap();
+    /// let image = api.image_of(&champion).await.unwrap();
+    /// # })
+    /// ```
+    #[cfg(feature = "image")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "image")))]
+    pub async fn image_of<T: HasImage>(&self, item: &T) -> Result<DynamicImage, ClientError> {
+        self.get_image(self.base_url.join(&format!(
+            "cdn/{}/img/{}",
+            &self.version,
+            item.image_path()
+        ))?)
+        .await
+    }
+
+    /// Returns an [image::DynamicImage].
+    ///
+    /// Keep in mind that this is a spritesheet image. You will have to cut out
+    /// the appropriate piece using the information on the [Image](crate::models::shared::Image).
+    ///
+    /// ```no_run
+    /// # tokio_test::block_on(async {
+    /// use ddragon::AsyncClient;
+    ///
+    /// let api = AsyncClient::new("./cache").await.unwrap();
+    /// let champion = api.champion("MonkeyKing").await.unwrap();
+    /// let sprite = api.sprite_of(&champion).await.unwrap();
+    /// # })
+    /// ```
+    #[cfg(feature = "image")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "image")))]
+    pub async fn sprite_of<T: HasImage>(&self, item: &T) -> Result<DynamicImage, ClientError> {
+        self.get_image(self.base_url.join(&format!(
+            "cdn/{}/img/{}",
+            &self.version,
+            item.sprite_path()
+        ))?)
+        .await
+    }
+
+    /// Returns the PNG-encoded bytes of a single icon, cut out of the sprite
+    /// sheet it belongs to. Sprite sheets are kept in memory once downloaded,
+    /// so cropping other icons from the same sheet does not fetch it again.
+    ///
+    /// ```no_run
+    /// # tokio_test::block_on(async {
+    /// use ddragon::AsyncClient;
+    ///
+    /// let api = AsyncClient::new("./cache").await.unwrap();
+    /// let champion = api.champion("MonkeyKing").await.unwrap();
+    /// let icon = api.crop_icon(&champion.image).await.unwrap();
+    /// # })
+    /// ```
+    #[cfg(feature = "image")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "image")))]
+    pub async fn crop_icon(&self, image: &Image) -> Result<Vec<u8>, ClientError> {
+        let sprite_url =
+            self.base_url.join(&format!("cdn/{}/img/{}", &self.version, image.sprite_path()))?;
+        let cache_key = sprite_url.to_string();
+
+        let cached_icon = self
+            .sprites
+            .lock()
+            .ok()
+            .and_then(|sprites| sprites.get(&cache_key).map(|sprite| image.crop_from(sprite)));
+        let icon = match cached_icon {
+            Some(icon) => icon,
+            None => {
+                let sprite = self.get_image(sprite_url).await?;
+                let icon = image.crop_from(&sprite);
+                if let Ok(mut sprites) = self.sprites.lock() {
+                    sprites.insert(cache_key, sprite);
+                }
+                icon
+            }
+        };
+
+        let mut png_bytes = Vec::new();
+        icon.write_to(&mut Cursor::new(&mut png_bytes), ImageOutputFormat::Png)?;
+        Ok(png_bytes)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use mockito::{Server, ServerGuard};
+
+    async fn create_mock_client() -> (ServerGuard, String, AsyncClient) {
+        let server = Server::new_async().await;
+        let url = server.url();
+        (
+            server,
+            url.clone(),
+            AsyncClient {
+                agent: MiddlewareClientBuilder::new(Client::new()).build(),
+                uncached_agent: MiddlewareClientBuilder::new(Client::new()).build(),
+                cache_dir: None,
+                version: "0.0.0".to_owned(),
+                locale: "en_US".to_owned(),
+                locale_fallback: None,
+                empty_datasets: EmptyDatasets::Allow,
+                concurrency: 8,
+                versions_ttl: Duration::from_secs(5 * 60),
+                local_preprocessor: None,
+                base_url: Url::parse(&url).unwrap(),
+                data_url: Url::parse(&format!("{}/cdn/0.0.0/data/en_US/", url)).unwrap(),
+                #[cfg(feature = "image")]
+                sprites: Default::default(),
+            },
+        )
+    }
+
+    fn mock_champion(id: &str) -> Champion {
+        let image = |full: String, group: &str| crate::models::shared::Image {
+            full,
+            sprite: "sprite0.png".to_owned(),
+            group: group.to_owned(),
+            x: 0,
+            y: 0,
+            w: 48,
+            h: 48,
+        };
+        Champion {
+            id: id.to_owned(),
+            key: "0".to_owned(),
+            name: id.to_owned(),
+            title: String::new(),
+            image: image(format!("{id}.png"), "champion"),
+            skins: vec![],
+            lore: String::new(),
+            blurb: String::new(),
+            allytips: vec![],
+            enemytips: vec![],
+            tags: vec![],
+            partype: "Mana".to_owned(),
+            info: crate::models::champion::Info { attack: 0, defense: 0, magic: 0, difficulty: 0 },
+            stats: Default::default(),
+            spells: vec![],
+            passive: crate::models::champion::Passive {
+                name: String::new(),
+                description: String::new(),
+                image: image(format!("{id}Passive.png"), "passive"),
+            },
+            recommended: vec![],
+        }
+    }
+
+    fn mock_champion_data(champions: &[Champion], short: bool) -> String {
+        let data: serde_json::Map<String, serde_json::Value> = champions
+            .iter()
+            .map(|champion| {
+                let mut value = serde_json::to_value(champion).unwrap();
+                if short {
+                    value["version"] = "0.0.0".into();
+                }
+                (champion.id.clone(), value)
+            })
+            .collect();
+        serde_json::json!({ "format": "standAloneComplex", "version": "0.0.0", "data": data })
+            .to_string()
+    }
+
+    mod create {
+        use super::*;
+
+        #[tokio::test]
+        async fn with_base_url_and_version_fetches_from_base_url() {
+            let mut server = Server::new_async().await;
+            let _mock = server
+                .mock("GET", "/cdn/1.1.1/data/en_US/data.json")
+                .with_status(200)
+                .with_header("Content-Type", "application/json")
+                .with_body(r#"["value"]"#)
+                .create_async()
+                .await;
+
+            let client =
+                AsyncClient::with_base_url_and_version(&server.url(), "1.1.1").await.unwrap();
+
+            assert_eq!(client.version, "1.1.1");
+            assert_eq!(client.get_data::<Vec<String>>("data.json").await.unwrap(), ["value"]);
+        }
+
+        #[tokio::test]
+        async fn with_existing_version_skips_version_request() {
+            let client = AsyncClient::with_existing_version(Client::new(), "1.1.1").await.unwrap();
+
+            assert_eq!(client.version, "1.1.1");
+        }
+
+        #[tokio::test]
+        async fn cached_versions_are_reused_and_used_offline() {
+            let mut server = Server::new_async().await;
+            let url = server.url();
+            let dir = std::env::temp_dir().join("ddragon-cached-versions-async");
+            let _ = std::fs::remove_dir_all(&dir);
+            let builder = || AsyncClientBuilder::new().server(&url).cache(dir.to_str().unwrap());
+
+            let mock = server
+                .mock("GET", "/api/versions.json")
+                .with_status(200)
+                .with_header("Content-Type", "application/json")
+                .with_body(r#"["1.1.1", "1.1.0"]"#)
+                .expect(1)
+                .create_async()
+                .await;
+            assert_eq!(builder().build().await.unwrap().version, "1.1.1");
+            let client = builder().build().await.unwrap();
+            assert_eq!(client.versions().await.unwrap(), ["1.1.1", "1.1.0"]);
+            mock.assert_async().await;
+
+            let _mock =
+                server.mock("GET", "/api/versions.json").with_status(500).create_async().await;
+            let client = builder().versions_ttl(Duration::ZERO).build().await.unwrap();
+            assert_eq!(client.version, "1.1.1");
+        }
+
+        #[tokio::test]
+        async fn result_ok_if_at_least_one_version() {
+            let mut server = Server::new_async().await;
+            let _mock = server
+                .mock("GET", "/api/versions.json")
+                .with_status(200)
+                .with_header("Content-Type", "application/json")
+                .with_body(r#"["0.0.0"]"#)
+                .create_async()
+                .await;
+
+            let maybe_client = AsyncClientBuilder::new().server(&server.url()).build().await;
+
+            assert!(maybe_client.is_ok());
+            assert_eq!(maybe_client.unwrap().version, "0.0.0");
+        }
+
+        #[tokio::test]
+        async fn result_ok_first_version_in_list() {
+            let mut server = Server::new_async().await;
+            let _mock = server
+                .mock("GET", "/api/versions.json")
+                .with_status(200)
+                .with_header("Content-Type", "application/json")
+                .with_body(r#"["0.0.0", "1.1.1", "2.2.2"]"#)
+                .create_async()
+                .await;
+
+            let maybe_client = AsyncClientBuilder::new().server(&server.url()).build().await;
+
+            assert!(maybe_client.is_ok());
+            assert_eq!(maybe_client.unwrap().version, "0.0.0");
+        }
+
+        #[tokio::test]
+        async fn result_err_unexpected_if_versions_are_html() {
+            let mut server = Server::new_async().await;
+            let _mock = server
+                .mock("GET", "/api/versions.json")
+                .with_status(200)
+                .with_header("Content-Type", "text/html")
+                .with_body("<html><body>Service Unavailable</body></html>")
+                .create_async()
+                .await;
+
+            match AsyncClientBuilder::new().server(&server.url()).build().await {
+                Err(ClientError::UnexpectedVersions { status, snippet }) => {
+                    assert_eq!(status, Some(200));
+                    assert!(snippet.starts_with("<html>"));
+                }
+                Err(other) => panic!("expected UnexpectedVersions, got {other:?}"),
+                Ok(_) => panic!("expected UnexpectedVersions, got a client"),
+            }
+        }
+
+        #[tokio::test]
+        async fn result_err_unexpected_if_versions_unavailable() {
+            let mut server = Server::new_async().await;
+            let _mock = server
+                .mock("GET", "/api/versions.json")
+                .with_status(503)
+                .with_header("Content-Type", "text/html")
+                .with_body("<html><body>Service Unavailable</body></html>")
+                .create_async()
+                .await;
+
+            match AsyncClientBuilder::new().server(&server.url()).build().await {
+                Err(ClientError::UnexpectedVersions { status, snippet }) => {
+                    assert_eq!(status, Some(503));
+                    assert!(snippet.starts_with("<html>"));
+                }
+                Err(other) => panic!("expected UnexpectedVersions, got {other:?}"),
+                Ok(_) => panic!("expected UnexpectedVersions, got a client"),
+            }
+        }
+
+        #[tokio::test]
+        async fn result_err_timeout_if_server_never_responds() {
+            let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+            let client = AsyncClientBuilder::new()
+                .server(&format!("http://{}", listener.local_addr().unwrap()))
+                .version("0.0.0")
+                .timeout(Duration::from_millis(100))
+                .build()
+                .await
+                .unwrap();
+
+            assert!(matches!(client.items().await, Err(ClientError::Timeout)));
+        }
+
+        #[tokio::test]
+        async fn result_err_server_unavailable() {
+            assert!(AsyncClientBuilder::new()
+                .server("https://dead-server.notadomain")
+                .build()
+                .await
+                .is_err());
+        }
+
+        #[tokio::test]
+        async fn result_ok_from_local_mirror() {
+            let dir = std::env::temp_dir().join("ddragon-local-mirror-async");
+            std::fs::create_dir_all(dir.join("api")).unwrap();
+            std::fs::create_dir_all(dir.join("cdn/0.0.0/data/en_US")).unwrap();
+            std::fs::write(dir.join("api/versions.json"), r#"["0.0.0"]"#).unwrap();
+            std::fs::write(dir.join("cdn/0.0.0/data/en_US/data.json"), r#"{"hello":"world"}"#)
+                .unwrap();
+
+            let client =
+                AsyncClientBuilder::new().local_dir(dir.to_str().unwrap()).build().await.unwrap();
+            let data = client
+                .get_data::<std::collections::HashMap<String, String>>("./data.json")
+                .await
+                .unwrap();
+
+            assert_eq!(client.version, "0.0.0");
+            assert_eq!(data.get("hello"), Some(&"world".to_owned()));
+        }
+
+        #[tokio::test]
+        async fn local_preprocessor_applies_to_local_files() {
+            let dir = std::env::temp_dir().join("ddragon-local-preprocessor-async");
+            std::fs::create_dir_all(dir.join("api")).unwrap();
+            std::fs::create_dir_all(dir.join("cdn/0.0.0/data/en_US")).unwrap();
+            std::fs::write(dir.join("api/versions.json"), r#"["0.0.0"]"#).unwrap();
+            std::fs::write(dir.join("cdn/0.0.0/data/en_US/data.json"), "\u{feff}[\"value\"]")
+                .unwrap();
+
+            let strict =
+                AsyncClientBuilder::new().local_dir(dir.to_str().unwrap()).build().await.unwrap();
+            assert!(strict.get_data::<Vec<String>>("./data.json").await.is_err());
+
+            let relaxed = AsyncClientBuilder::new()
+                .local_dir(dir.to_str().unwrap())
+                .local_preprocessor(|bytes| match bytes.strip_prefix("\u{feff}".as_bytes()) {
+                    Some(stripped) => stripped.to_vec(),
+                    None => bytes,
+                })
+                .build()
+                .await
+                .unwrap();
+            assert_eq!(
+                relaxed.get_data::<Vec<String>>("./data.json").await.unwrap(),
+                vec!["value"]
+            );
+        }
+
+        #[test]
+        fn from_vars_reads_version_cache_dir_and_locale() {
+            let vars = std::collections::HashMap::from([
+                ("DDRAGON_VERSION", "1.2.3"),
+                ("DDRAGON_CACHE_DIR", "./ddragon-from-env"),
+                ("DDRAGON_LOCALE", "ko_KR"),
+            ]);
+
+            let builder =
+                AsyncClientBuilder::from_vars(|name| vars.get(name).map(|value| value.to_string()));
+
+            assert_eq!(builder.version.as_deref(), Some("1.2.3"));
+            assert_eq!(builder.cache.as_deref(), Some("./ddragon-from-env"));
+            assert_eq!(builder.locale, "ko_KR");
+
+            let builder = AsyncClientBuilder::from_vars(|_| None);
+            assert_eq!(builder.version, None);
+            assert_eq!(builder.cache, None);
+            assert_eq!(builder.locale, "en_US");
+        }
+
+        #[tokio::test]
+        async fn result_err_no_versions_in_list() {
+            let mut server = Server::new_async().await;
+            let _mock = server
+                .mock("GET", "/api/versions.json")
+                .with_status(200)
+                .with_header("Content-Type", "application/json")
+                .with_body(r#"[]"#)
+                .create_async()
+                .await;
+
+            assert!(AsyncClientBuilder::new().server(&server.url()).build().await.is_err());
+        }
+
+        #[tokio::test]
+        async fn result_err_cannot_deserialize() {
+            let mut server = Server::new_async().await;
+            let _mock = server
+                .mock("GET", "/api/versions.json")
+                .with_status(200)
+                .with_body(r#"some non-deserializable content"#)
+                .create_async()
+                .await;
+
+            assert!(AsyncClientBuilder::new().server(&server.url()).build().await.is_err());
+        }
+
+        #[tokio::test]
+        async fn result_ok_manual_version() {
+            let mut server = Server::new_async().await;
+            let _mock = server
+                .mock("GET", "/api/versions.json")
+                .with_status(200)
+                .with_header("Content-Type", "application/json")
+                .with_body(r#"["0.0.0", "1.1.1", "2.2.2"]"#)
+                .create_async()
+                .await;
+
+            let maybe_client =
+                AsyncClientBuilder::new().server(&server.url()).version("3.3.3").build().await;
+
+            assert!(maybe_client.is_ok());
+            assert_eq!(maybe_client.unwrap().version, "3.3.3");
+        }
+    }
+
+    mod requests {
+        use super::*;
+
+        #[tokio::test]
+        async fn health_check_ok_if_versions_listed() {
+            let (mut server, _url, client) = create_mock_client().await;
+            let _mock = server
+                .mock("GET", "/api/versions.json")
+                .with_status(200)
+                .with_header("Content-Type", "application/json")
+                .with_body(r#"["0.0.0"]"#)
+                .create_async()
+                .await;
+
+            assert!(client.health_check().await.is_ok());
+        }
+
+        #[tokio::test]
+        async fn health_check_err_if_no_versions() {
+            let (mut server, _url, client) = create_mock_client().await;
+            let _mock = server
+                .mock("GET", "/api/versions.json")
+                .with_status(200)
+                .with_header("Content-Type", "application/json")
+                .with_body("[]")
+                .create_async()
+                .await;
+
+            assert!(matches!(client.health_check().await, Err(ClientError::NoLatestVersion)));
+        }
+
+        #[tokio::test]
+        async fn get_data_url_constructs_expected_baseurl() {
+            let (_server, url, client) = create_mock_client().await;
+            assert_eq!(
+                client.get_data_url().unwrap().as_str(),
+                format!("{}/cdn/0.0.0/data/en_US/", url)
+            );
+        }
+
+        #[tokio::test]
+        async fn get_data_url_follows_version_changes() {
+            let (_server, url, mut client) = create_mock_client().await;
+            client.version = "1.1.1".to_owned();
+            assert_eq!(
+                client.get_data_url().unwrap().as_str(),
+                format!("{}/cdn/1.1.1/data/en_US/", url)
+            );
+        }
+
+        #[tokio::test]
+        async fn challenge_icon_urls_resolve_under_cdn_img() {
+            let (_server, url, client) = create_mock_client().await;
+            let challenge: Challenge = serde_json::from_value(serde_json::json!({
+                "id": 1,
+                "name": "",
+                "description": "",
+                "shortDescription": "",
+                "hasLeaderboard": false,
+                "levelToIconPath": {
+                    "IRON": "/challenges-images/1-IRON.png",
+                    "GOLD": "https://example.com/1-GOLD.png"
+                },
+                "thresholds": {}
+            }))
+            .unwrap();
+
+            let urls: Vec<(ChallengeTier, String)> = client
+                .challenge_icon_urls(&challenge)
+                .unwrap()
+                .into_iter()
+                .map(|(tier, url)| (tier, url.to_string()))
+                .collect();
+            assert_eq!(
+                urls,
+                [
+                    (ChallengeTier::Iron, format!("{}/cdn/img/challenges-images/1-IRON.png", url)),
+                    (ChallengeTier::Gold, "https://example.com/1-GOLD.png".to_owned()),
+                ]
+            );
+        }
+
+        #[tokio::test]
+        async fn splash_art_url_checked_requires_the_skin() {
+            let (mut server, url, client) = create_mock_client().await;
+            let mut champion = mock_champion("MonkeyKing");
+            champion.skins = vec![crate::models::champion::Skin {
+                id: "62000".to_owned(),
+                num: 0,
+                name: "default".to_owned(),
+                chromas: false,
+            }];
+            let _mock = server
+                .mock("GET", "/cdn/0.0.0/data/en_US/champion/MonkeyKing.json")
+                .with_status(200)
+                .with_header("Content-Type", "application/json")
+                .with_body(mock_champion_data(&[champion], false))
+                .create_async()
+                .await;
+
+            assert_eq!(
+                client.splash_art_url_checked("MonkeyKing", 0).await.unwrap().unwrap().as_str(),
+                format!("{}/cdn/img/champion/splash/MonkeyKing_0.jpg", url)
+            );
+            assert_eq!(client.splash_art_url_checked("MonkeyKing", 7).await.unwrap(), None);
+        }
+
+        #[tokio::test]
+        async fn champion_tile_url_is_unversioned() {
+            let (_server, url, client) = create_mock_client().await;
+            assert_eq!(
+                client.champion_tile_url("MonkeyKing").unwrap().as_str(),
+                format!("{}/cdn/img/champion/tiles/MonkeyKing_0.jpg", url)
+            );
+        }
+
+        #[tokio::test]
+        async fn passive_image_url_uses_passive_image() {
+            let (_server, url, client) = create_mock_client().await;
+            let champion = mock_champion("MonkeyKing");
+
+            assert_eq!(
+                client.passive_image_url(&champion).unwrap().as_str(),
+                format!("{}/cdn/0.0.0/img/passive/MonkeyKingPassive.png", url)
+            );
+        }
+
+        #[tokio::test]
+        async fn spell_icon_url_uses_spell_image() {
+            let (_server, url, client) = create_mock_client().await;
+            let spell = crate::models::champion::test::spell(serde_json::json!({
+                "image": {
+                    "full": "MonkeyKingDoubleAttack.png",
+                    "sprite": "spell8.png",
+                    "group": "spell",
+                    "x": 0, "y": 0, "w": 48, "h": 48
+                }
+            }));
+
+            assert_eq!(
+                client.spell_icon_url(&spell).unwrap().as_str(),
+                format!("{}/cdn/0.0.0/img/spell/MonkeyKingDoubleAttack.png", url)
+            );
+        }
+
+        #[tokio::test]
+        async fn with_realm_adopts_cdn_and_version() {
+            let (_server, _url, client) = create_mock_client().await;
+            let realm: Realm = serde_json::from_value(serde_json::json!({
+                "n": {},
+                "v": "14.1.1",
+                "l": "en_GB",
+                "cdn": "https://cdn.example.com/cdn",
+                "dd": "14.1.2"
+            }))
+            .unwrap();
+            let client = client.with_realm(&realm).unwrap();
+
+            assert_eq!(client.version, "14.1.2");
+            assert_eq!(
+                client.get_data_url().unwrap().as_str(),
+                "https://cdn.example.com/cdn/14.1.2/data/en_US/"
+            );
+            assert_eq!(
+                client.image_url(&mock_champion("Ahri").summary()).unwrap().as_str(),
+                "https://cdn.example.com/cdn/14.1.2/img/champion/Ahri.png"
+            );
+        }
+
+        #[tokio::test]
+        async fn fetch_image_downloads_and_caches_bytes() {
+            let (mut server, _url, client) = create_mock_client().await;
+            let _mock = server
+                .mock("GET", "/cdn/0.0.0/img/item/1001.png")
+                .with_status(200)
+                .with_header("Content-Type", "image/png")
+                .with_body([0x89, b'P', b'N', b'G'])
+                .expect(1)
+                .create_async()
+                .await;
+            let dir = std::env::temp_dir().join("ddragon-fetch-image-async");
+            let _ = std::fs::remove_dir_all(&dir);
+            let agent = MiddlewareClientBuilder::new(Client::new())
+                .with(Cache(HttpCache {
+                    mode: CacheMode::ForceCache,
+                    manager: CACacheManager { path: dir },
+                    options: HttpCacheOptions::default(),
+                }))
+                .build();
+            let client = AsyncClient { agent, ..client };
+
+            let image = crate::models::shared::Image {
+                full: "1001.png".to_owned(),
+                sprite: "item0.png".to_owned(),
+                group: "item".to_owned(),
+                x: 0,
+                y: 0,
+                w: 48,
+                h: 48,
+            };
+            assert_eq!(client.fetch_image(&image).await.unwrap(), [0x89, b'P', b'N', b'G']);
+            assert_eq!(client.fetch_image(&image).await.unwrap(), [0x89, b'P', b'N', b'G']);
+            _mock.assert_async().await;
+        }
 
         #[tokio::test]
-        async fn result_ok_if_at_least_one_version() {
-            let mut server = Server::new_async().await;
+        async fn image_url_of_summary_is_square_portrait() {
+            let (_server, url, client) = create_mock_client().await;
+            let summary = mock_champion("Ahri").summary();
+
+            assert_eq!(
+                client.image_url(&summary).unwrap().as_str(),
+                format!("{}/cdn/0.0.0/img/champion/Ahri.png", url)
+            );
+        }
+
+        #[tokio::test]
+        async fn champions_full_individually_fetches_each_champion() {
+            let (mut server, _url, client) = create_mock_client().await;
+            let champions = [mock_champion("MonkeyKing"), mock_champion("Ahri")];
+            let _list = server
+                .mock("GET", "/cdn/0.0.0/data/en_US/champion.json")
+                .with_status(200)
+                .with_header("Content-Type", "application/json")
+                .with_body(mock_champion_data(&champions, true))
+                .create_async()
+                .await;
+            let mut details = Vec::new();
+            for champion in &champions {
+                details.push(
+                    server
+                        .mock(
+                            "GET",
+                            &*format!("/cdn/0.0.0/data/en_US/champion/{}.json", champion.id),
+                        )
+                        .with_status(200)
+                        .with_header("Content-Type", "application/json")
+                        .with_body(mock_champion_data(std::slice::from_ref(champion), false))
+                        .create_async()
+                        .await,
+                );
+            }
+
+            let fetched: Vec<Champion> = client
+                .champions_full_individually()
+                .await
+                .unwrap()
+                .map(Result::unwrap)
+                .collect()
+                .await;
+            assert_eq!(fetched, vec![champions[1].clone(), champions[0].clone()]);
+        }
+
+        #[tokio::test]
+        async fn champions_full_with_progress_reports_bytes_read() {
+            let (mut server, _url, client) = create_mock_client().await;
+            let mut body: serde_json::Value =
+                serde_json::from_str(&mock_champion_data(&[mock_champion("Ahri")], false)).unwrap();
+            body["keys"] = serde_json::json!({ "0": "Ahri" });
+            let body = body.to_string();
             let _mock = server
-                .mock("GET", "/api/versions.json")
+                .mock("GET", "/cdn/0.0.0/data/en_US/championFull.json")
                 .with_status(200)
                 .with_header("Content-Type", "application/json")
-                .with_body(r#"["0.0.0"]"#)
+                .with_body(&body)
                 .create_async()
                 .await;
 
-            let maybe_client = AsyncClientBuilder::new().server(&server.url()).build().await;
+            let mut reports = Vec::new();
+            let champions_full = client
+                .champions_full_with_progress(|read, total| reports.push((read, total)))
+                .await
+                .unwrap();
 
-            assert!(maybe_client.is_ok());
-            assert_eq!(maybe_client.unwrap().version, "0.0.0");
+            assert_eq!(champions_full.len(), 1);
+            assert_eq!(reports.last(), Some(&(body.len() as u64, Some(body.len() as u64))));
         }
 
         #[tokio::test]
-        async fn result_ok_first_version_in_list() {
-            let mut server = Server::new_async().await;
+        async fn champions_full_with_progress_checks_empty_datasets() {
+            let (mut server, _url, client) = create_mock_client().await;
+            let client = AsyncClient { empty_datasets: EmptyDatasets::Error, ..client };
             let _mock = server
-                .mock("GET", "/api/versions.json")
+                .mock("GET", "/cdn/0.0.0/data/en_US/championFull.json")
                 .with_status(200)
                 .with_header("Content-Type", "application/json")
-                .with_body(r#"["0.0.0", "1.1.1", "2.2.2"]"#)
+                .with_body(
+                    r#"{"type":"champion","format":"full","version":"0.0.0","data":{},"keys":{}}"#,
+                )
                 .create_async()
                 .await;
 
-            let maybe_client = AsyncClientBuilder::new().server(&server.url()).build().await;
+            let rejected = client.champions_full_with_progress(|_, _| {}).await;
+            assert!(matches!(rejected, Err(ClientError::EmptyDataset { .. })));
+        }
 
-            assert!(maybe_client.is_ok());
-            assert_eq!(maybe_client.unwrap().version, "0.0.0");
+        #[tokio::test]
+        async fn champions_full_with_progress_err_on_error_status() {
+            let (mut server, _url, client) = create_mock_client().await;
+            let _mock = server
+                .mock("GET", "/cdn/0.0.0/data/en_US/championFull.json")
+                .with_status(500)
+                .with_header("Content-Type", "application/json")
+                .with_body(
+                    r#"{"type":"champion","format":"full","version":"0.0.0","data":{},"keys":{}}"#,
+                )
+                .create_async()
+                .await;
+
+            let mut reports = Vec::new();
+            let result = client
+                .champions_full_with_progress(|read, total| reports.push((read, total)))
+                .await;
+
+            assert!(matches!(result, Err(ClientError::AsyncRequest(_))));
+            assert!(reports.is_empty());
         }
 
         #[tokio::test]
-        async fn result_err_server_unavailable() {
-            assert!(AsyncClientBuilder::new()
-                .server("https://dead-server.notadomain")
-                .build()
-                .await
-                .is_err());
+        async fn get_data_err_if_server_unavailable() {
+            let (_server, _url, client) = create_mock_client().await;
+            assert!(client.get_data::<String>("/fake-endpoint").await.is_err());
         }
 
         #[tokio::test]
-        async fn result_err_no_versions_in_list() {
-            let mut server = Server::new_async().await;
+        async fn get_data_err_if_data_not_deserializable() {
+            let (mut server, _url, client) = create_mock_client().await;
             let _mock = server
-                .mock("GET", "/api/versions.json")
+                .mock("GET", "/cdn/0.0.0/data/en_US/data.json")
                 .with_status(200)
                 .with_header("Content-Type", "application/json")
-                .with_body(r#"[]"#)
+                .with_body(r#"no chance to deserialize this"#)
                 .create_async()
                 .await;
 
-            assert!(AsyncClientBuilder::new().server(&server.url()).build().await.is_err());
+            assert!(client.get_data::<String>("./data.json").await.is_err());
         }
 
         #[tokio::test]
-        async fn result_err_cannot_deserialize() {
-            let mut server = Server::new_async().await;
+        async fn get_data_err_reports_json_position() {
+            let (mut server, _url, client) = create_mock_client().await;
             let _mock = server
-                .mock("GET", "/api/versions.json")
+                .mock("GET", "/cdn/0.0.0/data/en_US/data.json")
                 .with_status(200)
-                .with_body(r#"some non-deserializable content"#)
+                .with_header("Content-Type", "application/json")
+                .with_body("{\n  \"value\": oops\n}")
                 .create_async()
                 .await;
 
-            assert!(AsyncClientBuilder::new().server(&server.url()).build().await.is_err());
+            match client.get_data::<std::collections::HashMap<String, String>>("./data.json").await
+            {
+                Err(ClientError::Json { line, column, snippet, .. }) => {
+                    assert_eq!((line, column), (2, 12));
+                    assert!(snippet.contains("\"value\": oops"));
+                }
+                other => panic!("expected a JSON error, got {other:?}"),
+            }
         }
 
         #[tokio::test]
-        async fn result_ok_manual_version() {
-            let mut server = Server::new_async().await;
+        async fn get_data_ok_deserializes_to_type() {
+            let (mut server, _url, client) = create_mock_client().await;
             let _mock = server
-                .mock("GET", "/api/versions.json")
+                .mock("GET", "/cdn/0.0.0/data/en_US/data.json")
                 .with_status(200)
                 .with_header("Content-Type", "application/json")
-                .with_body(r#"["0.0.0", "1.1.1", "2.2.2"]"#)
+                .with_body(r#"["value"]"#)
                 .create_async()
                 .await;
 
-            let maybe_client =
-                AsyncClientBuilder::new().server(&server.url()).version("3.3.3").build().await;
+            assert_eq!(
+                client.get_data::<Vec<String>>("./data.json").await.unwrap(),
+                vec!["value".to_owned()]
+            );
+        }
 
-            assert!(maybe_client.is_ok());
-            assert_eq!(maybe_client.unwrap().version, "3.3.3");
+        #[tokio::test]
+        async fn verify_checksums_rejects_mismatched_body_without_caching() {
+            let mut server = Server::new_async().await;
+            let dir = std::env::temp_dir().join("ddragon-checksum-async");
+            let _ = std::fs::remove_dir_all(&dir);
+            let client = AsyncClientBuilder::new()
+                .server(&server.url())
+                .version("0.0.0")
+                .cache(dir.to_str().unwrap())
+                .verify_checksums(true)
+                .build()
+                .await
+                .unwrap();
+            let _bad = server
+                .mock("GET", "/cdn/0.0.0/data/en_US/bad.json")
+                .with_status(200)
+                .with_header("Content-Type", "application/json")
+                .with_header("Content-MD5", "AAAAAAAAAAAAAAAAAAAAAA==")
+                .with_body(r#"["value"]"#)
+                .create_async()
+                .await;
+            let _good = server
+                .mock("GET", "/cdn/0.0.0/data/en_US/good.json")
+                .with_status(200)
+                .with_header("Content-Type", "application/json")
+                .with_header("Content-MD5", "ilvfVP2SGN5DbA8E8AyJbw==")
+                .with_body(r#"["value"]"#)
+                .create_async()
+                .await;
+
+            let bad = client.get_data::<Vec<String>>("bad.json").await;
+            assert!(matches!(bad, Err(ClientError::ChecksumMismatch { .. })));
+            assert!(!client.is_cached("bad.json").await);
+
+            assert_eq!(client.get_data::<Vec<String>>("good.json").await.unwrap(), ["value"]);
+            assert!(client.is_cached("good.json").await);
         }
-    }
 
-    mod requests {
-        use super::*;
+        #[tokio::test]
+        async fn is_cached_checks_cache_for_data_file() {
+            let (_server, url, client) = create_mock_client().await;
+            assert!(!client.is_cached("data.json").await);
+
+            let dir = std::env::temp_dir().join("ddragon-is-cached-async");
+            let key = format!("GET:{}/cdn/0.0.0/data/en_US/data.json", url);
+            cacache::write(&dir, key, b"[]").await.unwrap();
+            let client =
+                AsyncClient { cache_dir: Some(dir.to_str().unwrap().to_owned()), ..client };
+
+            assert!(client.is_cached("data.json").await);
+            assert!(!client.is_cached("other.json").await);
+        }
 
         #[tokio::test]
-        async fn get_data_url_constructs_expected_baseurl() {
+        async fn cache_age_reads_cache_timestamps() {
             let (_server, url, client) = create_mock_client().await;
-            assert_eq!(
-                client.get_data_url().unwrap().as_str(),
-                format!("{}/cdn/0.0.0/data/en_US/", url)
-            );
+            assert_eq!(client.cache_age("data.json").await, None);
+
+            let dir = std::env::temp_dir().join("ddragon-cache-age-async");
+            let key = format!("GET:{}/cdn/0.0.0/data/en_US/data.json", url);
+            cacache::write(&dir, key, b"[]").await.unwrap();
+            let client =
+                AsyncClient { cache_dir: Some(dir.to_str().unwrap().to_owned()), ..client };
+
+            assert!(client.cache_age("data.json").await.unwrap() < Duration::from_secs(60));
+            assert_eq!(client.cache_age("other.json").await, None);
         }
 
         #[tokio::test]
-        async fn get_data_err_if_server_unavailable() {
-            let (_server, _url, client) = create_mock_client().await;
-            assert!(client.get_data::<String>("/fake-endpoint").await.is_err());
+        async fn prewarm_skips_cached_files_and_reports_failures() {
+            let (mut server, url, client) = create_mock_client().await;
+            let _cached = server
+                .mock("GET", "/cdn/0.0.0/data/en_US/cached.json")
+                .expect(0)
+                .create_async()
+                .await;
+            let _fresh = server
+                .mock("GET", "/cdn/0.0.0/data/en_US/fresh.json")
+                .with_status(200)
+                .with_header("Content-Type", "application/json")
+                .with_body("[]")
+                .expect(1)
+                .create_async()
+                .await;
+            let _missing = server
+                .mock("GET", "/cdn/0.0.0/data/en_US/missing.json")
+                .with_status(404)
+                .create_async()
+                .await;
+
+            let dir = std::env::temp_dir().join("ddragon-prewarm-async");
+            let _ = std::fs::remove_dir_all(&dir);
+            let key = format!("GET:{}/cdn/0.0.0/data/en_US/cached.json", url);
+            cacache::write(&dir, key, b"[]").await.unwrap();
+            let agent = MiddlewareClientBuilder::new(Client::new())
+                .with(Cache(HttpCache {
+                    mode: CacheMode::ForceCache,
+                    manager: CACacheManager { path: dir.clone() },
+                    options: HttpCacheOptions::default(),
+                }))
+                .build();
+            let client =
+                AsyncClient { agent, cache_dir: Some(dir.to_str().unwrap().to_owned()), ..client };
+
+            let result = client.prewarm(&["cached.json", "fresh.json", "missing.json"]).await;
+            let Err(ClientError::Prewarm { failures }) = result else {
+                panic!("expected a prewarm error, got {:?}", result);
+            };
+            assert_eq!(failures.len(), 1);
+            assert_eq!(failures[0].0, "missing.json");
+            assert!(client.is_cached("fresh.json").await);
+            _cached.assert_async().await;
+            _fresh.assert_async().await;
         }
 
         #[tokio::test]
-        async fn get_data_err_if_data_not_deserializable() {
+        async fn fetch_with_stats_reports_size_and_cache_hits() {
             let (mut server, _url, client) = create_mock_client().await;
             let _mock = server
                 .mock("GET", "/cdn/0.0.0/data/en_US/data.json")
                 .with_status(200)
                 .with_header("Content-Type", "application/json")
-                .with_body(r#"no chance to deserialize this"#)
+                .with_body(r#"["a","b"]"#)
+                .expect(1)
                 .create_async()
                 .await;
 
-            assert!(client.get_data::<String>("./data.json").await.is_err());
+            let dir = std::env::temp_dir().join("ddragon-fetch-stats-async");
+            let _ = std::fs::remove_dir_all(&dir);
+            let agent = MiddlewareClientBuilder::new(Client::new())
+                .with(Cache(HttpCache {
+                    mode: CacheMode::ForceCache,
+                    manager: CACacheManager { path: dir },
+                    options: HttpCacheOptions::default(),
+                }))
+                .build();
+            let client = AsyncClient { agent, ..client };
+
+            let (data, stats) = client.fetch_with_stats::<Vec<String>>("data.json").await.unwrap();
+            assert_eq!(data, vec!["a", "b"]);
+            assert_eq!(stats.bytes, 9);
+            assert!(!stats.from_cache);
+
+            let (_, stats) = client.fetch_with_stats::<Vec<String>>("data.json").await.unwrap();
+            assert_eq!(stats.bytes, 9);
+            assert!(stats.from_cache);
         }
 
         #[tokio::test]
-        async fn get_data_ok_deserializes_to_type() {
+        async fn revalidate_sends_if_modified_since() {
+            let mut server = Server::new_async().await;
+            let dir = std::env::temp_dir().join("ddragon-revalidate-async");
+            let _ = std::fs::remove_dir_all(&dir);
+            let client = AsyncClientBuilder::new()
+                .server(&server.url())
+                .cache(dir.to_str().unwrap())
+                .version("0.0.0")
+                .revalidate(true)
+                .build()
+                .await
+                .unwrap();
+
+            let _first = server
+                .mock("GET", "/file.json")
+                .with_status(200)
+                .with_header("Content-Type", "application/json")
+                .with_header("Cache-Control", "no-cache")
+                .with_header("Last-Modified", "Wed, 21 Oct 2015 07:28:00 GMT")
+                .with_body(r#"["first"]"#)
+                .expect(1)
+                .create_async()
+                .await;
+            assert_eq!(client.get_cdn("file.json").await.unwrap(), serde_json::json!(["first"]));
+
+            let not_modified = server
+                .mock("GET", "/file.json")
+                .match_header("If-Modified-Since", "Wed, 21 Oct 2015 07:28:00 GMT")
+                .with_status(304)
+                .create_async()
+                .await;
+            assert_eq!(client.get_cdn("file.json").await.unwrap(), serde_json::json!(["first"]));
+            not_modified.assert_async().await;
+        }
+
+        #[tokio::test]
+        async fn get_cdn_joins_onto_base_url() {
             let (mut server, _url, client) = create_mock_client().await;
             let _mock = server
-                .mock("GET", "/cdn/0.0.0/data/en_US/data.json")
+                .mock("GET", "/cdn/languages.json")
+                .with_status(200)
+                .with_header("Content-Type", "application/json")
+                .with_body(r#"["en_US","ko_KR"]"#)
+                .create_async()
+                .await;
+
+            assert_eq!(
+                client.get_cdn("cdn/languages.json").await.unwrap(),
+                serde_json::json!(["en_US", "ko_KR"])
+            );
+        }
+
+        #[tokio::test]
+        async fn estimate_patch_size_sums_content_lengths() {
+            let (mut server, _url, client) = create_mock_client().await;
+            let _mock = server
+                .mock("HEAD", mockito::Matcher::Regex(r"^/cdn/0\.0\.0/data/en_US/.+\.json$".into()))
+                .match_header("Accept-Language", "en-US")
+                .with_status(200)
+                .with_header("Content-Length", "100")
+                .expect(crate::fetch_options::DATA_FILES.len())
+                .create_async()
+                .await;
+
+            assert_eq!(
+                client.estimate_patch_size().await.unwrap(),
+                100 * crate::fetch_options::DATA_FILES.len() as u64
+            );
+            _mock.assert_async().await;
+        }
+
+        #[tokio::test]
+        async fn get_data_with_overrides_locale_and_version() {
+            let (mut server, _url, client) = create_mock_client().await;
+            let _mock = server
+                .mock("GET", "/cdn/1.1.1/data/ko_KR/data.json")
+                .match_header("Accept-Language", "ko-KR")
                 .with_status(200)
                 .with_header("Content-Type", "application/json")
                 .with_body(r#"["value"]"#)
                 .create_async()
                 .await;
 
+            let options = FetchOptions::new().locale("ko_KR").version("1.1.1");
             assert_eq!(
-                client.get_data::<Vec<String>>("./data.json").await.unwrap(),
+                client.get_data_with::<Vec<String>>("data.json", &options).await.unwrap(),
                 vec!["value".to_owned()]
             );
         }
+
+        #[tokio::test]
+        async fn empty_datasets_are_flagged_when_configured() {
+            let (mut server, _url, client) = create_mock_client().await;
+            let _mock = server
+                .mock("GET", "/cdn/0.0.0/data/en_US/data.json")
+                .with_status(200)
+                .with_header("Content-Type", "application/json")
+                .with_body(r#"{"type":"champion","data":{}}"#)
+                .create_async()
+                .await;
+
+            let allowed = client.get_data::<Value>("data.json").await;
+            assert!(allowed.is_ok());
+
+            let client = AsyncClient { empty_datasets: EmptyDatasets::Warn, ..client };
+            assert!(client.get_data::<Value>("data.json").await.is_ok());
+
+            let client = AsyncClient { empty_datasets: EmptyDatasets::Error, ..client };
+            let rejected = client.get_data::<Value>("data.json").await;
+            assert!(
+                matches!(rejected, Err(ClientError::EmptyDataset { endpoint }) if endpoint == "data.json")
+            );
+        }
+
+        #[tokio::test]
+        async fn checked_data_reports_where_parsing_failed() {
+            let (mut server, _url, client) = create_mock_client().await;
+            let client = AsyncClient { empty_datasets: EmptyDatasets::Warn, ..client };
+            let _mock = server
+                .mock("GET", "/cdn/0.0.0/data/en_US/data.json")
+                .with_status(200)
+                .with_header("Content-Type", "application/json")
+                .with_body("[\n\"a\",\n1\n]")
+                .create_async()
+                .await;
+
+            let failed = client.get_data::<Vec<String>>("data.json").await;
+            assert!(
+                matches!(failed, Err(ClientError::Json { line: 3, column: 1, snippet, .. }) if snippet.contains("1"))
+            );
+        }
+
+        #[tokio::test]
+        async fn fetch_with_stats_checks_empty_datasets() {
+            let (mut server, _url, client) = create_mock_client().await;
+            let client = AsyncClient { empty_datasets: EmptyDatasets::Error, ..client };
+            let _mock = server
+                .mock("GET", "/cdn/0.0.0/data/en_US/data.json")
+                .with_status(200)
+                .with_header("Content-Type", "application/json")
+                .with_body(r#"{"type":"champion","data":{}}"#)
+                .create_async()
+                .await;
+
+            let rejected = client.fetch_with_stats::<Value>("data.json").await;
+            assert!(
+                matches!(rejected, Err(ClientError::EmptyDataset { endpoint }) if endpoint == "data.json")
+            );
+        }
+
+        #[tokio::test]
+        async fn get_data_fills_missing_names_from_fallback_locale() {
+            let (mut server, _url, client) = create_mock_client().await;
+            let client = AsyncClient { locale_fallback: Some("en_US".to_owned()), ..client };
+            let _primary = server
+                .mock("GET", "/cdn/0.0.0/data/ko_KR/data.json")
+                .with_status(200)
+                .with_header("Content-Type", "application/json")
+                .with_body(r#"[{"name": "부츠"}, {"name": ""}]"#)
+                .create_async()
+                .await;
+            let fallback = server
+                .mock("GET", "/cdn/0.0.0/data/en_US/data.json")
+                .with_status(200)
+                .with_header("Content-Type", "application/json")
+                .with_body(r#"[{"name": "Boots"}, {"name": "Faerie Charm"}]"#)
+                .create_async()
+                .await;
+
+            let options = FetchOptions::new().locale("ko_KR");
+            let data: Vec<std::collections::HashMap<String, String>> =
+                client.get_data_with("data.json", &options).await.unwrap();
+
+            assert_eq!(data[0]["name"], "부츠");
+            assert_eq!(data[1]["name"], "Faerie Charm");
+            fallback.assert_async().await;
+        }
+
+        #[cfg(feature = "image")]
+        #[tokio::test]
+        async fn crop_icon_crops_and_reuses_sprite() {
+            let (mut server, _url, client) = create_mock_client().await;
+            let mut sprite_bytes = Vec::new();
+            DynamicImage::new_rgba8(4, 2)
+                .write_to(&mut Cursor::new(&mut sprite_bytes), ImageOutputFormat::Png)
+                .unwrap();
+            let mock = server
+                .mock("GET", "/cdn/0.0.0/img/sprite/champion0.png")
+                .with_status(200)
+                .with_header("Content-Type", "image/png")
+                .with_body(sprite_bytes)
+                .expect(1)
+                .create_async()
+                .await;
+
+            let image = Image {
+                full: "Aatrox.png".to_owned(),
+                sprite: "champion0.png".to_owned(),
+                group: "champion".to_owned(),
+                x: 2,
+                y: 0,
+                w: 2,
+                h: 2,
+            };
+            let icon = load_from_memory(&client.crop_icon(&image).await.unwrap()).unwrap();
+            assert_eq!((icon.width(), icon.height()), (2, 2));
+
+            assert!(client.crop_icon(&Image { x: 0, ..image }).await.is_ok());
+            mock.assert_async().await;
+        }
     }
 }
@@ -1,13 +1,82 @@
 #![cfg_attr(docsrs, doc(cfg(feature = "sync")))]
 #![warn(missing_docs)]
 
-use ureq::http::{Request, Response};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use ureq::http::header::{HeaderValue, IF_MODIFIED_SINCE, LAST_MODIFIED};
+use ureq::http::{Method, Request, Response, StatusCode};
 use ureq::middleware::{Middleware, MiddlewareNext};
 use ureq::{Body, Error, SendBody};
 
+use crate::fetch_options::CACHE_STATUS_HEADER;
+
+/// A storage backend for cached responses. Implement this to keep cached
+/// data somewhere other than the local disk (e.g. Redis or S3).
+///
+/// Only the synchronous [Client](crate::Client) uses this. The async client
+/// caches through `http-cache-reqwest` instead; pass a `ClientWithMiddleware`
+/// with your own `CacheManager` to `AsyncClientBuilder::agent_with_middleware`
+/// to store its data elsewhere.
+pub trait Cache: Send + Sync {
+    /// Returns the data stored under `key`, if there is any.
+    fn get(&self, key: &str) -> Option<Vec<u8>>;
+
+    /// Stores `value` under `key`. Caching is best-effort, so failures should
+    /// not be surfaced.
+    fn put(&self, key: &str, value: &[u8]);
+
+    /// Returns whether any data is stored under `key`. By default this reads
+    /// the data, so backends that can check more cheaply should override it.
+    fn contains(&self, key: &str) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Returns when the data under `key` was stored, if there is any and the
+    /// backend tracks it. By default no timestamps are tracked.
+    fn written_at(&self, _key: &str) -> Option<SystemTime> {
+        None
+    }
+}
+
+/// The default [Cache], which stores data on disk via `cacache`.
+#[derive(Clone)]
+pub struct DiskCache {
+    directory: String,
+}
+
+impl DiskCache {
+    /// Creates a new disk cache in the specified directory. Cache file
+    /// structure beyond that is dictated by `cacache`.
+    pub fn new(directory: &str) -> Self {
+        Self { directory: directory.to_owned() }
+    }
+}
+
+impl Cache for DiskCache {
+    fn get(&self, key: &str) -> Option<Vec<u8>> {
+        cacache::read_sync(&self.directory, key).ok()
+    }
+
+    fn put(&self, key: &str, value: &[u8]) {
+        let _ = cacache::write_sync(&self.directory, key, value);
+    }
+
+    fn contains(&self, key: &str) -> bool {
+        matches!(cacache::metadata_sync(&self.directory, key), Ok(Some(_)))
+    }
+
+    fn written_at(&self, key: &str) -> Option<SystemTime> {
+        let metadata = cacache::metadata_sync(&self.directory, key).ok()??;
+        let millis = u64::try_from(metadata.time).ok()?;
+        UNIX_EPOCH.checked_add(Duration::from_millis(millis))
+    }
+}
+
 /// Handles caching responses locally.
 pub struct CacheMiddleware {
-    directory: String,
+    cache: Arc<dyn Cache>,
+    revalidate: bool,
 }
 
 impl CacheMiddleware {
@@ -15,40 +84,111 @@ impl CacheMiddleware {
     /// files to go in specified. Cache file structure beyond that is dictated
     /// by `cacache`.
     pub fn new(directory: &str) -> Self {
-        Self { directory: directory.to_owned() }
+        Self::with_cache(DiskCache::new(directory))
     }
+
+    /// Creates a new middleware which stores responses in the provided
+    /// [Cache] backend.
+    pub fn with_cache<C: Cache + 'static>(cache: C) -> Self {
+        Self::from_shared(Arc::new(cache))
+    }
+
+    pub(crate) fn from_shared(cache: Arc<dyn Cache>) -> Self {
+        Self { cache, revalidate: false }
+    }
+
+    /// Configures whether cached responses are revalidated with the server
+    /// before being used, by sending `If-Modified-Since` with the
+    /// `Last-Modified` date stored alongside them. On `304 Not Modified`, or
+    /// if the server can't be reached, the cached body is used; responses
+    /// cached without a `Last-Modified` date are fetched again in full.
+    ///
+    /// This is off by default, as ddragon's versioned files never change, but
+    /// helps with mirrors that update files in place.
+    pub fn revalidate(mut self, revalidate: bool) -> Self {
+        self.revalidate = revalidate;
+        self
+    }
+}
+
+/// The key the `Last-Modified` date of a cached response is stored under.
+/// Fragments are never sent in requests, so this can't clash with a body.
+fn last_modified_key(cache_key: &str) -> String {
+    format!("{cache_key}#last-modified")
+}
+
+fn cached_response(data: Vec<u8>, is_image: bool) -> Result<Response<Body>, Error> {
+    let data_type = if is_image { "image/png" } else { "application/json" };
+    Ok(Response::builder()
+        .header("Content-Type", data_type)
+        .header("Content-Length", data.len())
+        .header(CACHE_STATUS_HEADER, "HIT")
+        .status(200)
+        .body(Body::builder().mime_type(data_type).data(data))?)
 }
 
 impl Middleware for CacheMiddleware {
     fn handle(
         &self,
-        request: Request<SendBody>,
+        mut request: Request<SendBody>,
         next: MiddlewareNext,
     ) -> Result<Response<Body>, Error> {
-        // We always want an up-to-date version list.
-        if request.uri().path().ends_with("/api/versions.json") {
+        // We always want an up-to-date version list, and realms point at
+        // whichever version a region is currently serving. Only `GET` bodies
+        // are worth caching.
+        let path = request.uri().path();
+        if request.method() != Method::GET
+            || path.ends_with("/api/versions.json")
+            || path.contains("/realms/")
+        {
             return next.handle(request);
         }
 
         let is_image = request.uri().path().ends_with(".png");
         let cache_key = request.uri().to_string();
-        if let Ok(data) = cacache::read_sync(&self.directory, &cache_key) {
-            let data_type = if is_image { "image/png" } else { "application/json" };
-            return Ok(Response::builder()
-                .header("Content-Type", data_type)
-                .header("Content-Length", data.len())
-                .status(200)
-                .body(Body::builder().mime_type(data_type).data(data))?);
-        }
+        let cached = self.cache.get(&cache_key);
+        let last_modified = match &cached {
+            Some(_) if self.revalidate => self
+                .cache
+                .get(&last_modified_key(&cache_key))
+                .and_then(|date| HeaderValue::from_bytes(&date).ok()),
+            _ => None,
+        };
 
-        let mut response = next.handle(request)?;
+        let mut response = match (cached, last_modified) {
+            (Some(data), Some(last_modified)) => {
+                trace!("revalidating cached {}", cache_key);
+                request.headers_mut().insert(IF_MODIFIED_SINCE, last_modified);
+                match next.handle(request) {
+                    Ok(response) if response.status() == StatusCode::NOT_MODIFIED => {
+                        return cached_response(data, is_image);
+                    }
+                    Ok(response) => response,
+                    Err(e) => {
+                        debug!("using cached {} after failing to revalidate it: {}", cache_key, e);
+                        return cached_response(data, is_image);
+                    }
+                }
+            }
+            (Some(data), None) if !self.revalidate => {
+                trace!("cache hit for {}", cache_key);
+                return cached_response(data, is_image);
+            }
+            _ => {
+                trace!("cache miss for {}", cache_key);
+                next.handle(request)?
+            }
+        };
         if response.status() != 200 {
             return Ok(response);
         }
+        if let Some(last_modified) = response.headers().get(LAST_MODIFIED) {
+            self.cache.put(&last_modified_key(&cache_key), last_modified.as_bytes());
+        }
 
         let body_mut = response.body_mut();
         if let Ok(body) = body_mut.read_to_vec() {
-            let _ = cacache::write_sync(&self.directory, cache_key, body.clone());
+            self.cache.put(&cache_key, &body);
             let mut body_builder = Body::builder();
             let mut reponse_builder = Response::builder();
             if let Some(mime_type) = body_mut.mime_type() {
@@ -69,7 +209,7 @@ impl Middleware for CacheMiddleware {
 mod tests {
     use super::*;
     use mockito::Server;
-    use std::{env::temp_dir, fs::remove_dir_all, path::Path};
+    use std::{collections::HashMap, env::temp_dir, fs::remove_dir_all, path::Path, sync::Mutex};
     use ureq::Agent;
 
     fn build_agent(cache_dir: &Path) -> Agent {
@@ -127,4 +267,111 @@ mod tests {
         assert_eq!(response.status(), 200);
         assert_eq!(response.into_body().read_to_string().unwrap(), "some example text");
     }
+
+    #[derive(Default)]
+    struct MemoryCache {
+        entries: Mutex<HashMap<String, Vec<u8>>>,
+    }
+
+    impl Cache for MemoryCache {
+        fn get(&self, key: &str) -> Option<Vec<u8>> {
+            self.entries.lock().unwrap().get(key).cloned()
+        }
+
+        fn put(&self, key: &str, value: &[u8]) {
+            self.entries.lock().unwrap().insert(key.to_owned(), value.to_vec());
+        }
+    }
+
+    #[test]
+    fn custom_cache_backend_is_used() {
+        let mut server = Server::new();
+        let full_url = format!("{}/file.txt", server.url());
+
+        let cache = Arc::new(MemoryCache::default());
+        let agent: Agent = Agent::config_builder()
+            .middleware(CacheMiddleware::from_shared(cache.clone()))
+            .build()
+            .into();
+
+        {
+            let _m = server
+                .mock("GET", "/file.txt")
+                .with_status(200)
+                .with_body("some example text")
+                .create();
+
+            let _ = agent.get(&full_url).call().unwrap();
+        }
+
+        assert_eq!(cache.get(&full_url).unwrap(), b"some example text");
+
+        let response = agent.get(&full_url).call().unwrap();
+        assert_eq!(response.into_body().read_to_string().unwrap(), "some example text");
+    }
+
+    #[test]
+    fn head_requests_are_not_cached() {
+        let mut server = Server::new();
+        let full_url = format!("{}/file.txt", server.url());
+
+        let cache = Arc::new(MemoryCache::default());
+        let agent: Agent = Agent::config_builder()
+            .middleware(CacheMiddleware::from_shared(cache.clone()))
+            .build()
+            .into();
+
+        let _m = server.mock("HEAD", "/file.txt").with_status(200).create();
+        let _ = agent.head(&full_url).call().unwrap();
+
+        assert!(cache.get(&full_url).is_none());
+    }
+
+    #[test]
+    fn revalidation_uses_last_modified() {
+        let mut server = Server::new();
+        let full_url = format!("{}/file.txt", server.url());
+        let read = |agent: &Agent| {
+            agent.get(&full_url).call().unwrap().into_body().read_to_string().unwrap()
+        };
+
+        let cache = Arc::new(MemoryCache::default());
+        let agent: Agent = Agent::config_builder()
+            .middleware(CacheMiddleware::from_shared(cache.clone()).revalidate(true))
+            .build()
+            .into();
+
+        {
+            let _m = server
+                .mock("GET", "/file.txt")
+                .with_status(200)
+                .with_header("Last-Modified", "Wed, 21 Oct 2015 07:28:00 GMT")
+                .with_body("first")
+                .create();
+            assert_eq!(read(&agent), "first");
+        }
+
+        {
+            let _m = server
+                .mock("GET", "/file.txt")
+                .match_header("If-Modified-Since", "Wed, 21 Oct 2015 07:28:00 GMT")
+                .with_status(304)
+                .create();
+            assert_eq!(read(&agent), "first");
+        }
+
+        {
+            let _m = server
+                .mock("GET", "/file.txt")
+                .with_status(200)
+                .with_header("Last-Modified", "Thu, 22 Oct 2015 07:28:00 GMT")
+                .with_body("second")
+                .create();
+            assert_eq!(read(&agent), "second");
+        }
+        assert_eq!(
+            cache.get(&format!("{full_url}#last-modified")).unwrap(),
+            b"Thu, 22 Oct 2015 07:28:00 GMT"
+        );
+    }
 }
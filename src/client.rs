@@ -5,28 +5,43 @@
 use mockito;
 
 #[cfg(feature = "image")]
-use image::{load_from_memory, DynamicImage};
+use image::{load_from_memory, DynamicImage, ImageOutputFormat};
 
 #[cfg(feature = "image")]
-use std::io::Read;
+use std::{collections::HashMap, io::Cursor, sync::Mutex};
+
+use std::{
+    borrow::Cow,
+    io::{self, Read},
+    path::PathBuf,
+    sync::Arc,
+    time::{Duration, Instant, SystemTime},
+};
 
 use serde::de::DeserializeOwned;
+use serde_json::Value;
 use ureq::Agent;
 use url::Url;
 
-use crate::cache_middleware::CacheMiddleware;
+use crate::cache_middleware::{Cache, CacheMiddleware, DiskCache};
+use crate::integrity::ChecksumMiddleware;
+use crate::locale_fallback::{fill_missing_names, has_missing_names};
 
-#[cfg(feature = "image")]
 use crate::models::shared::HasImage;
+#[cfg(feature = "image")]
+use crate::models::shared::Image;
 use crate::models::tft::{
     self, Arenas, Augments, HeroAugments, Queues, Regalia, Tacticians, Traits,
 };
 use crate::{
+    fetch_options::{accept_language, check_empty_dataset, CACHE_STATUS_HEADER, DATA_FILES},
     models::{
-        champion::ChampionWrapper, Challenges, Champion, Champions, ChampionsFull, Items, Maps,
-        MissionAssets, ProfileIcons, Runes, SpellBuffs, SummonerSpells, Translations,
+        challenges::{Challenge, ChallengeTier},
+        champion::{ChampionWrapper, Spell},
+        Challenges, Champion, Champions, ChampionsFull, Items, Maps, MissionAssets, ProfileIcons,
+        Realm, Runes, SpellBuffs, Stickers, SummonerSpells, Translations,
     },
-    ClientError,
+    ClientError, EmptyDatasets, FetchOptions, FetchStats,
 };
 
 /// Used for building a [Client] with custom options.
@@ -34,7 +49,21 @@ pub struct ClientBuilder {
     server: String,
     agent: Option<Agent>,
     cache: Option<String>,
+    local_dir: Option<String>,
+    local_preprocessor: Option<LocalPreprocessor>,
+    cache_backend: Option<Arc<dyn Cache>>,
     version: Option<String>,
+    locale: String,
+    locale_fallback: Option<String>,
+    empty_datasets: EmptyDatasets,
+    concurrency: usize,
+    verify_checksums: bool,
+    max_idle_connections: Option<usize>,
+    max_idle_connections_per_host: Option<usize>,
+    max_idle_age: Option<Duration>,
+    timeout: Option<Duration>,
+    versions_ttl: Duration,
+    revalidate: bool,
 }
 
 ///
@@ -76,8 +105,59 @@ impl ClientBuilder {
             server: "https://ddragon.leagueoflegends.com".to_owned(),
             agent: None,
             cache: None,
+            local_dir: None,
+            local_preprocessor: None,
+            cache_backend: None,
             version: None,
+            locale: "en_US".to_owned(),
+            locale_fallback: None,
+            empty_datasets: EmptyDatasets::Allow,
+            concurrency: 8,
+            verify_checksums: false,
+            max_idle_connections: None,
+            max_idle_connections_per_host: None,
+            max_idle_age: None,
+            timeout: None,
+            versions_ttl: Duration::from_secs(5 * 60),
+            revalidate: false,
+        }
+    }
+
+    /// Creates a [ClientBuilder] configured from the environment:
+    ///
+    /// - `DDRAGON_CACHE_DIR` sets the [cache](Self::cache) directory.
+    /// - `DDRAGON_VERSION` pins the [version](Self::version).
+    /// - `DDRAGON_LOCALE` sets the [locale](Self::locale).
+    ///
+    /// Unset variables leave the corresponding option at its default, so with
+    /// none set this is the same as [new](Self::new) (no caching, latest
+    /// version, `en_US`). Options set on the returned builder take precedence
+    /// over the environment.
+    ///
+    /// ```no_run
+    /// use ddragon::ClientBuilder;
+    ///
+    /// // DDRAGON_CACHE_DIR is ignored here, since a cache is set explicitly.
+    /// let client = ClientBuilder::from_env().cache("./cache").build().unwrap();
+    /// ```
+    pub fn from_env() -> Self {
+        Self::from_vars(|name| std::env::var(name).ok())
+    }
+
+    /// Does the work of [from_env](Self::from_env), looking variables up with
+    /// `var` so it can be tested without touching the real environment.
+    fn from_vars(var: impl Fn(&str) -> Option<String>) -> Self {
+        let mut builder = Self::new();
+        if let Some(dir) = var("DDRAGON_CACHE_DIR") {
+            builder = builder.cache(&dir);
+        }
+        if let Some(version) = var("DDRAGON_VERSION") {
+            builder = builder.version(&version);
         }
+        if let Some(locale) = var("DDRAGON_LOCALE") {
+            builder = builder.locale(&locale);
+        }
+        builder
     }
 
     /// Configures a custom [Agent] for making network requests.
@@ -92,6 +172,64 @@ impl ClientBuilder {
         self
     }
 
+    /// Configures a custom [Cache] backend to store downloaded data in. This
+    /// takes precedence over a cache directory set with `.cache()`. There's no
+    /// async equivalent, see [Cache] for how to swap out its storage instead.
+    ///
+    /// ```no_run
+    /// use ddragon::{cache_middleware::DiskCache, ClientBuilder};
+    ///
+    /// let client = ClientBuilder::new().cache_backend(DiskCache::new("./cache")).build().unwrap();
+    /// ```
+    pub fn cache_backend<C: Cache + 'static>(mut self, cache: C) -> Self {
+        self.cache_backend = Some(Arc::new(cache));
+        self
+    }
+
+    /// Configures the client to read data from a local mirror of ddragon
+    /// instead of the network, which is useful for offline development and
+    /// CI. The directory should be laid out like the ddragon host, e.g.
+    /// `<dir>/api/versions.json` and `<dir>/cdn/<version>/data/en_US/*.json`.
+    /// If the mirror has no `api/versions.json` (such as an extracted
+    /// `dragontail` archive moved into a `cdn` folder), the version must be
+    /// set with `.version()`.
+    ///
+    /// ```no_run
+    /// use ddragon::ClientBuilder;
+    ///
+    /// let client = ClientBuilder::new().local_dir("./ddragon").build().unwrap();
+    /// ```
+    pub fn local_dir(mut self, dir: &str) -> Self {
+        self.local_dir = Some(dir.to_owned());
+        self
+    }
+
+    /// Configures a function that transforms the raw bytes of every JSON file
+    /// read from the [local mirror](Self::local_dir) before it is parsed, e.x.
+    /// to tolerate quirks in hand-edited files. Data fetched over the network
+    /// is always parsed strictly.
+    ///
+    /// ```no_run
+    /// use ddragon::ClientBuilder;
+    ///
+    /// // Hand-edited files sometimes gain a byte order mark, which serde_json rejects.
+    /// let client = ClientBuilder::new()
+    ///     .local_dir("./ddragon")
+    ///     .local_preprocessor(|bytes| match bytes.strip_prefix(b"\xEF\xBB\xBF") {
+    ///         Some(stripped) => stripped.to_vec(),
+    ///         None => bytes,
+    ///     })
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn local_preprocessor(
+        mut self,
+        preprocess: impl Fn(Vec<u8>) -> Vec<u8> + Send + Sync + 'static,
+    ) -> Self {
+        self.local_preprocessor = Some(Arc::new(preprocess));
+        self
+    }
+
     /// Configure the ddragon version for making requests. Normally this should
     /// not be needed, as the latest version is always used.
     pub fn version(mut self, version: &str) -> Self {
@@ -99,8 +237,111 @@ impl ClientBuilder {
         self
     }
 
+    /// Configure the locale data is fetched in, e.x. `ko_KR`. Defaults to
+    /// `en_US`.
+    pub fn locale(mut self, locale: &str) -> Self {
+        self.locale = locale.to_owned();
+        self
+    }
+
+    /// Configure a locale to fall back to when data fetched in the
+    /// [locale](Self::locale) has empty or missing names, which happens for
+    /// newly added entries that have not been translated yet.
+    ///
+    /// Only names are filled in from the fallback, everything else is kept
+    /// as fetched. Note that whenever a gap is found the whole dataset is
+    /// fetched a second time in the fallback locale, doubling the network
+    /// cost of that request.
+    pub fn locale_fallback(mut self, locale: &str) -> Self {
+        self.locale_fallback = Some(locale.to_owned());
+        self
+    }
+
+    /// Configure what happens when a dataset comes back valid but empty, e.x.
+    /// `{"data":{}}` while the CDN is partway through deploying a patch.
+    /// Defaults to [EmptyDatasets::Allow], since some datasets can
+    /// legitimately be small or empty. Checking a dataset means parsing it
+    /// twice, once to inspect it and once into the model.
+    pub fn empty_datasets(mut self, policy: EmptyDatasets) -> Self {
+        self.empty_datasets = policy;
+        self
+    }
+
+    /// Verifies downloaded data against the `Content-MD5` header sent with
+    /// it, if any, before it is cached. A mismatch, e.g. from a truncated
+    /// download, fails with [ClientError::ChecksumMismatch] and nothing is
+    /// cached. Responses without the header are accepted as-is. Disabled by
+    /// default, and has no effect if a custom agent is specified.
+    pub fn verify_checksums(mut self, verify: bool) -> Self {
+        self.verify_checksums = verify;
+        self
+    }
+
+    /// Configures the maximum number of idle connections kept open for reuse
+    /// across all hosts. Defaults to `ureq`'s default of 10. Has no effect if
+    /// a custom agent is specified.
+    pub fn max_idle_connections(mut self, max: usize) -> Self {
+        self.max_idle_connections = Some(max);
+        self
+    }
+
+    /// Configures the maximum number of idle connections kept open for reuse
+    /// per host. Defaults to `ureq`'s default of 3, which is usually worth
+    /// raising when fetching many images. Has no effect if a custom agent is
+    /// specified.
+    pub fn max_idle_connections_per_host(mut self, max: usize) -> Self {
+        self.max_idle_connections_per_host = Some(max);
+        self
+    }
+
+    /// Configures how long an idle connection is kept alive before it is
+    /// closed. Defaults to `ureq`'s default of 15 seconds. Has no effect if a
+    /// custom agent is specified.
+    pub fn max_idle_age(mut self, age: Duration) -> Self {
+        self.max_idle_age = Some(age);
+        self
+    }
+
+    /// Configures how long a whole request, including reading the response,
+    /// may take before it fails with [ClientError::Timeout]. By default
+    /// `ureq` sets no overall limit. Has no effect if a custom agent is
+    /// specified.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Configures how long a cached version list is reused before it's
+    /// fetched again. Defaults to five minutes, so frequently built clients
+    /// don't hit the network every time. If fetching fails, a cached list of
+    /// any age is used instead, so a client can be built offline once the
+    /// list has been cached. Has no effect without a cache.
+    pub fn versions_ttl(mut self, ttl: Duration) -> Self {
+        self.versions_ttl = ttl;
+        self
+    }
+
+    /// Configures whether cached responses are revalidated with the server
+    /// using `If-Modified-Since` before being used. See
+    /// [CacheMiddleware::revalidate] for details. Off by default, as ddragon's
+    /// versioned files never change. Has no effect if a custom agent is
+    /// specified.
+    pub fn revalidate(mut self, revalidate: bool) -> Self {
+        self.revalidate = revalidate;
+        self
+    }
+
+    /// Configures how many requests bulk operations, such as fetching every
+    /// champion individually, keep in flight at once. Defaults to 8, which is
+    /// gentle enough on the CDN to avoid being rate limited. Values below 1
+    /// are treated as 1.
+    pub fn concurrency(mut self, limit: usize) -> Self {
+        self.concurrency = limit.max(1);
+        self
+    }
+
     #[cfg(test)]
-    pub fn server(mut self, server: &str) -> Self {
+    fn server(mut self, server: &str) -> Self {
         self.server = server.to_owned();
         self
     }
@@ -115,32 +356,77 @@ impl ClientBuilder {
     /// - If a custom agent is specified, not specifying a cache directory will
     ///   result in images not being cached if you are using the `image` feature.
     pub fn build(self) -> Result<Client, ClientError> {
+        let cache = self
+            .cache_backend
+            .or_else(|| self.cache.map(|dir| Arc::new(DiskCache::new(&dir)) as Arc<dyn Cache>));
+
         let agent = match self.agent {
             Some(a) => a,
-            None => match self.cache.clone() {
-                Some(dir) => {
-                    Agent::config_builder().middleware(CacheMiddleware::new(&dir)).build().into()
+            None => {
+                let mut config = Agent::config_builder();
+                if let Some(cache) = cache.clone() {
+                    config = config.middleware(
+                        CacheMiddleware::from_shared(cache).revalidate(self.revalidate),
+                    );
                 }
-                None => Agent::new_with_defaults(),
-            },
+                // Added after the cache so that it sits closer to the network,
+                // and a mismatch fails before the response is cached.
+                if self.verify_checksums {
+                    config = config.middleware(ChecksumMiddleware);
+                }
+                if let Some(max) = self.max_idle_connections {
+                    config = config.max_idle_connections(max);
+                }
+                if let Some(max) = self.max_idle_connections_per_host {
+                    config = config.max_idle_connections_per_host(max);
+                }
+                if let Some(age) = self.max_idle_age {
+                    config = config.max_idle_age(age);
+                }
+                if let Some(timeout) = self.timeout {
+                    config = config.timeout_global(Some(timeout));
+                }
+                config.build().into()
+            }
         };
 
-        let base_url = Url::parse(&self.server)?;
+        let base_url = match self.local_dir {
+            Some(dir) => local_dir_url(&dir)?,
+            None => Url::parse(&self.server)?,
+        };
         let latest_version = if let Some(version) = self.version {
             version
         } else {
-            let version_list = agent
-                .get(base_url.join("/api/versions.json")?.as_str())
-                .call()
-                .map_err(Box::new)?
-                .into_body()
-                .read_json::<Vec<String>>()
-                .map_err(Box::new)?;
-
-            version_list.first().ok_or(ClientError::NoLatestVersion)?.to_owned()
+            get_cached_versions(
+                &agent,
+                cache.as_deref(),
+                &base_url,
+                self.local_preprocessor.as_ref(),
+                self.versions_ttl,
+            )?
+            .first()
+            .ok_or(ClientError::NoLatestVersion)?
+            .to_owned()
         };
+        debug!("using ddragon version {} from {}", latest_version, base_url);
 
-        Ok(Client { agent, version: latest_version, base_url })
+        let data_url = data_url_for(&base_url, &latest_version, &self.locale)?;
+
+        Ok(Client {
+            agent,
+            cache,
+            version: latest_version,
+            locale: self.locale,
+            locale_fallback: self.locale_fallback,
+            empty_datasets: self.empty_datasets,
+            concurrency: self.concurrency,
+            versions_ttl: self.versions_ttl,
+            local_preprocessor: self.local_preprocessor,
+            base_url,
+            data_url,
+            #[cfg(feature = "image")]
+            sprites: Default::default(),
+        })
     }
 }
 
@@ -154,9 +440,211 @@ impl Default for ClientBuilder {
 /// Provides access to the ddragon API.
 pub struct Client {
     agent: Agent,
+    cache: Option<Arc<dyn Cache>>,
     /// The current version of the API data reported back to us from the API.
     pub version: String,
+    locale: String,
+    locale_fallback: Option<String>,
+    empty_datasets: EmptyDatasets,
+    concurrency: usize,
+    versions_ttl: Duration,
+    local_preprocessor: Option<LocalPreprocessor>,
     base_url: Url,
+    data_url: Url,
+    #[cfg(feature = "image")]
+    sprites: Arc<Mutex<HashMap<String, DynamicImage>>>,
+}
+
+fn data_url_for(base_url: &Url, version: &str, locale: &str) -> Result<Url, url::ParseError> {
+    base_url.join(&format!("cdn/{}/data/{}/", version, locale))
+}
+
+/// Resolves a challenge icon path, which may already be a full URL, against
+/// the unversioned `cdn/img/` directory.
+fn challenge_icon_url(base_url: &Url, path: &str) -> Result<Url, url::ParseError> {
+    if let Ok(url) = Url::parse(path) {
+        return Ok(url);
+    }
+    let path = path.trim_start_matches('/');
+    base_url.join(&format!("cdn/img/{}", path.strip_prefix("cdn/img/").unwrap_or(path)))
+}
+
+fn local_dir_url(dir: &str) -> Result<Url, ClientError> {
+    let path = std::env::current_dir().map_err(ClientError::LocalFile)?.join(dir);
+    Url::from_directory_path(&path).map_err(|_| {
+        ClientError::LocalFile(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("{} is not a valid directory", path.display()),
+        ))
+    })
+}
+
+fn local_file_path(url: &Url) -> Result<PathBuf, ClientError> {
+    url.to_file_path().map_err(|_| {
+        ClientError::LocalFile(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("{url} is not a valid file path"),
+        ))
+    })
+}
+
+fn read_local_file(url: &Url) -> Result<Vec<u8>, ClientError> {
+    std::fs::read(local_file_path(url)?).map_err(ClientError::LocalFile)
+}
+
+/// Transforms the raw bytes of a local mirror file before they are parsed.
+type LocalPreprocessor = Arc<dyn Fn(Vec<u8>) -> Vec<u8> + Send + Sync>;
+
+fn read_local_data(
+    url: &Url,
+    preprocess: Option<&LocalPreprocessor>,
+) -> Result<Vec<u8>, ClientError> {
+    let bytes = read_local_file(url)?;
+    Ok(match preprocess {
+        Some(preprocess) => preprocess(bytes),
+        None => bytes,
+    })
+}
+
+/// Fetches the raw body at `url`, from the local mirror or over the network.
+/// Every JSON and image fetch goes through here, so they all share the same
+/// request behaviour.
+fn get_bytes(
+    agent: &Agent,
+    url: &Url,
+    locale: Option<&str>,
+    preprocess: Option<&LocalPreprocessor>,
+) -> Result<Vec<u8>, ClientError> {
+    fetch_bytes(agent, url, locale, preprocess).map(|(body, _)| body)
+}
+
+/// Like [get_bytes], but also reports whether the body came from the cache.
+fn fetch_bytes(
+    agent: &Agent,
+    url: &Url,
+    locale: Option<&str>,
+    preprocess: Option<&LocalPreprocessor>,
+) -> Result<(Vec<u8>, bool), ClientError> {
+    if url.scheme() == "file" {
+        debug!("reading {} from the local mirror", url);
+        return Ok((read_local_data(url, preprocess)?, false));
+    }
+
+    let mut request = agent.get(url.as_str());
+    if let Some(locale) = locale {
+        request = request.header("Accept-Language", accept_language(locale));
+    }
+    let response = request.call().map_err(Box::new)?;
+    let from_cache = response
+        .headers()
+        .get(CACHE_STATUS_HEADER)
+        .is_some_and(|status| status.as_bytes() == b"HIT");
+    let body = response.into_body().read_to_vec().map_err(Box::new)?;
+    debug!("fetched {} ({} bytes, from cache: {})", url, body.len(), from_cache);
+    Ok((body, from_cache))
+}
+
+fn get_json<T: DeserializeOwned>(
+    agent: &Agent,
+    url: &Url,
+    locale: Option<&str>,
+    preprocess: Option<&LocalPreprocessor>,
+) -> Result<T, ClientError> {
+    let body = get_bytes(agent, url, locale, preprocess)?;
+    serde_json::from_slice(&body).map_err(|e| ClientError::json(e, &body))
+}
+
+/// Fetches the version list, reporting anything that isn't a list of versions
+/// (e.g. an error page) as [ClientError::UnexpectedVersions].
+fn get_versions(
+    agent: &Agent,
+    base_url: &Url,
+    preprocess: Option<&LocalPreprocessor>,
+) -> Result<Vec<String>, ClientError> {
+    let versions_url = base_url.join("api/versions.json")?;
+    let (status, body) = if versions_url.scheme() == "file" {
+        (None, read_local_data(&versions_url, preprocess)?)
+    } else {
+        let mut response = agent
+            .get(versions_url.as_str())
+            .config()
+            .http_status_as_error(false)
+            .build()
+            .call()
+            .map_err(Box::new)?;
+        let status = response.status();
+        let body = response.body_mut().read_to_vec().map_err(Box::new)?;
+        if !status.is_success() {
+            return Err(ClientError::unexpected_versions(Some(status.as_u16()), &body));
+        }
+        (Some(status.as_u16()), body)
+    };
+
+    serde_json::from_slice(&body).map_err(|_| ClientError::unexpected_versions(status, &body))
+}
+
+/// Fetches the version list through `cache`, reusing a cached list younger
+/// than `ttl` and falling back to a cached list of any age if the fetch fails.
+fn get_cached_versions(
+    agent: &Agent,
+    cache: Option<&dyn Cache>,
+    base_url: &Url,
+    preprocess: Option<&LocalPreprocessor>,
+    ttl: Duration,
+) -> Result<Vec<String>, ClientError> {
+    let cache = match cache {
+        Some(cache) if base_url.scheme() != "file" => cache,
+        _ => return get_versions(agent, base_url, preprocess),
+    };
+
+    let key = base_url.join("api/versions.json")?.to_string();
+    let cached: Option<Vec<String>> =
+        cache.get(&key).and_then(|body| serde_json::from_slice(&body).ok());
+    let is_fresh = cache
+        .written_at(&key)
+        .and_then(|written_at| written_at.elapsed().ok())
+        .is_some_and(|age| age < ttl);
+    if let (Some(versions), true) = (&cached, is_fresh) {
+        trace!("cache hit for {}", key);
+        return Ok(versions.clone());
+    }
+
+    match get_versions(agent, base_url, preprocess) {
+        Ok(versions) => {
+            if !versions.is_empty() {
+                if let Ok(body) = serde_json::to_vec(&versions) {
+                    cache.put(&key, &body);
+                }
+            }
+            Ok(versions)
+        }
+        Err(e) => match cached {
+            Some(versions) => {
+                debug!("using cached versions after failing to fetch them: {}", e);
+                Ok(versions)
+            }
+            None => Err(e),
+        },
+    }
+}
+
+/// Wraps a reader, reporting the running byte count after every read.
+struct ProgressReader<R, F> {
+    inner: R,
+    read: u64,
+    total: Option<u64>,
+    progress: F,
+}
+
+impl<R: Read, F: FnMut(u64, Option<u64>)> Read for ProgressReader<R, F> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let count = self.inner.read(buf)?;
+        if count > 0 {
+            self.read += count as u64;
+            (self.progress)(self.read, self.total);
+        }
+        Ok(count)
+    }
 }
 
 macro_rules! create_endpoint {
@@ -188,242 +676,1368 @@ impl Client {
         ClientBuilder::new().cache(cache_dir).build()
     }
 
-    fn get_data_url(&self) -> Result<Url, url::ParseError> {
-        self.base_url.join(&format!("/cdn/{}/data/en_US/", &self.version))
+    /// Creates a new client configured from the environment. See
+    /// [ClientBuilder::from_env] for the variables that are read.
+    ///
+    /// ```no_run
+    /// use ddragon::Client;
+    ///
+    /// let api = Client::from_env().unwrap();
+    /// ```
+    pub fn from_env() -> Result<Self, ClientError> {
+        ClientBuilder::from_env().build()
     }
 
-    fn get_data<T: DeserializeOwned>(&self, endpoint: &str) -> Result<T, ClientError> {
-        let joined_url = self.get_data_url()?.join(endpoint)?;
-        let request_url = joined_url.as_str();
+    /// Creates a new client from an existing [Agent] and an already known
+    /// version, e.x. one saved from a previous run. Unlike the other
+    /// constructors this makes no network requests, as the version list is
+    /// never fetched.
+    ///
+    /// ```no_run
+    /// use ddragon::Client;
+    ///
+    /// let api = Client::with_existing_version(ureq::Agent::new_with_defaults(), "14.1.1").unwrap();
+    /// ```
+    pub fn with_existing_version(agent: Agent, version: &str) -> Result<Self, ClientError> {
+        ClientBuilder::new().agent(agent).version(version).build()
+    }
 
-        self.agent
-            .get(request_url)
-            .call()
-            .map_err(Box::new)?
-            .into_body()
-            .read_json::<T>()
-            .map_err(|e| Box::new(e).into())
+    /// Creates a new client that fetches from `base_url` instead of ddragon,
+    /// pinned to `version` so no version list is requested. This is mainly
+    /// for pointing a client at a local fixture or mock server in tests.
+    ///
+    /// ```no_run
+    /// use ddragon::Client;
+    ///
+    /// let api = Client::with_base_url_and_version("http://127.0.0.1:1234", "14.1.1").unwrap();
+    /// ```
+    pub fn with_base_url_and_version(base_url: &str, version: &str) -> Result<Self, ClientError> {
+        ClientBuilder { server: base_url.to_owned(), ..ClientBuilder::new() }
+            .version(version)
+            .build()
     }
 
-    create_endpoint!(challenges, "challenge", "challenges", Challenges);
-    create_endpoint!(champions, "champion", "champion", Champions);
-    create_endpoint!(champions_full, "complete champion", "championFull", ChampionsFull);
-    create_endpoint!(items, "item", "item", Items);
-    create_endpoint!(maps, "map", "map", Maps);
-    create_endpoint!(mission_assets, "mission asset", "mission-assets", MissionAssets);
-    create_endpoint!(profile_icons, "profile icon", "profileicon", ProfileIcons);
-    create_endpoint!(runes, "rune", "runesReforged", Runes);
-    create_endpoint!(spell_buffs, "spell buff", "spellbuffs", SpellBuffs);
-    create_endpoint!(summoner_spells, "summoner_spells", "summoner", SummonerSpells);
-    create_endpoint!(translations, "translation", "language", Translations);
-    create_endpoint!(tft_arenas, "TFT arena", "tft-arena", Arenas);
-    create_endpoint!(tft_augments, "TFT augment", "tft-augments", Augments);
-    create_endpoint!(tft_champions, "TFT champion", "tft-champion", tft::Champions);
-    create_endpoint!(tft_hero_augments, "TFT hero augment", "tft-hero-augments", HeroAugments);
-    create_endpoint!(tft_items, "TFT item", "tft-item", tft::Items);
-    create_endpoint!(tft_queues, "TFT queue", "tft-queues", Queues);
-    create_endpoint!(tft_regalia, "TFT regalia", "tft-regalia", Regalia);
-    create_endpoint!(tft_tacticians, "TFT tactician", "tft-tactician", Tacticians);
-    create_endpoint!(tft_traits, "TFT trait", "tft-trait", Traits);
+    /// Returns the [Cache] backend this client was configured with, if any.
+    pub fn cache(&self) -> Option<&dyn Cache> {
+        self.cache.as_deref()
+    }
 
-    /// Returns data for a single champion. The champion's name or numeric key
-    /// should not be used here -- this should be the key property on the
-    /// [Champion] struct. This is usually the name, but differs in a bunch of
-    /// cases (e.x. Wukong's key is MonkeyKing).
+    /// Checks that ddragon is reachable and reporting at least one version,
+    /// which makes this suitable for readiness probes. Only the small version
+    /// list is requested, and it is never served from the cache.
     ///
     /// ```no_run
     /// use ddragon::Client;
     ///
     /// let api = Client::new("./cache").unwrap();
-    /// let wukong = api.champion("MonkeyKing").unwrap();
+    /// if api.health_check().is_err() {
+    ///     eprintln!("ddragon is unreachable");
+    /// }
     /// ```
-    pub fn champion(&self, key: &str) -> Result<Champion, ClientError> {
-        self.get_data::<ChampionWrapper>(&format!("./champion/{key}.json"))?
-            .data
-            .get(key)
-            .cloned()
-            .ok_or(ClientError::NoChampionData)
+    pub fn health_check(&self) -> Result<(), ClientError> {
+        if get_versions(&self.agent, &self.base_url, self.local_preprocessor.as_ref())?.is_empty() {
+            return Err(ClientError::NoLatestVersion);
+        }
+        Ok(())
     }
 
-    #[cfg(feature = "image")]
-    fn get_image(&self, path: Url) -> Result<DynamicImage, ClientError> {
-        let response = self
-            .agent
-            .get(path.as_str())
-            .call()
-            .map_err(|e| std::convert::Into::<ClientError>::into(Box::new(e)))?;
-
-        // We don't want to assume we can just read_to_end cleanly, so ideally
-        // we get a header telling us how many bytes we can read. If we can't,
-        // using 1 as a default means parsing will always fail and produce an
-        // error that can be handled elsewhere.
-        let image_size_bytes = response
-            .headers()
-            .get("Content-Length")
-            .map(|s| s.to_str().unwrap_or("1"))
-            .unwrap_or("1")
-            .parse::<u64>()
-            .unwrap_or(1);
-
-        let mut image_buffer: Vec<u8> = vec![];
-        response.into_body().into_reader().take(image_size_bytes).read_to_end(&mut image_buffer)?;
-
-        load_from_memory(&image_buffer).map_err(|e| e.into())
+    /// Fetches every ddragon version, newest first, as listed in
+    /// `api/versions.json`. A cached list is reused for the builder's
+    /// [versions_ttl](ClientBuilder::versions_ttl), and used regardless of
+    /// age if fetching fails.
+    ///
+    /// ```no_run
+    /// use ddragon::Client;
+    ///
+    /// let api = Client::new("./cache").unwrap();
+    /// let versions = api.versions().unwrap();
+    /// ```
+    pub fn versions(&self) -> Result<Vec<String>, ClientError> {
+        get_cached_versions(
+            &self.agent,
+            self.cache.as_deref(),
+            &self.base_url,
+            self.local_preprocessor.as_ref(),
+            self.versions_ttl,
+        )
     }
 
-    /// Returns an [image::DynamicImage].
+    /// Fetches the versions a region is currently serving, e.x. `na` or
+    /// `euw`. Realms are never served from the cache.
     ///
     /// ```no_run
     /// use ddragon::Client;
     ///
     /// let api = Client::new("./cache").unwrap();
-    /// let champion = api.champion("MonkeyKing").unwrap();
-    /// let image = api.image_of(&champion).unwrap();
+    /// let realm = api.realm("euw").unwrap();
+    /// println!("EUW serves {:?}", realm.dd_version());
     /// ```
-    #[cfg(feature = "image")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "image")))]
-    pub fn image_of<T: HasImage>(&self, item: &T) -> Result<DynamicImage, ClientError> {
-        self.get_image(self.base_url.join(&format!(
-            "/cdn/{}/img/{}",
-            &self.version,
-            item.image_path()
-        ))?)
+    pub fn realm(&self, region: &str) -> Result<Realm, ClientError> {
+        let realm_url = self.base_url.join(&format!("realms/{}.json", region))?;
+        get_json(&self.agent, &realm_url, None, self.local_preprocessor.as_ref())
     }
 
-    /// Returns an [image::DynamicImage].
+    /// Switches this client to the CDN and Data Dragon version a region is
+    /// serving, so data and image URLs match what players in that region
+    /// see. The realm's `cdn` is expected to end in `/cdn`, as Riot's do.
     ///
-    /// Keep in mind that this is a spritesheet image. You will have to cut out
-    /// the appropriate piece using the information on the [Image](crate::models::shared::Image).
+    /// ```no_run
+    /// use ddragon::Client;
+    ///
+    /// let api = Client::new("./cache").unwrap();
+    /// let realm = api.realm("euw").unwrap();
+    /// let api = api.with_realm(&realm).unwrap();
+    /// ```
+    pub fn with_realm(mut self, realm: &Realm) -> Result<Self, ClientError> {
+        let cdn = realm.cdn.trim_end_matches('/');
+        self.base_url = Url::parse(&format!("{}/", cdn.strip_suffix("/cdn").unwrap_or(cdn)))?;
+        self.version = realm.dd_version().unwrap_or(&realm.v).to_owned();
+        self.data_url = data_url_for(&self.base_url, &self.version, &self.locale)?;
+        Ok(self)
+    }
+
+    /// Returns whether a dataset file, e.x. `item.json`, is cached for the
+    /// current version, without reading or parsing it. Always `false` when no
+    /// cache is configured.
     ///
     /// ```no_run
     /// use ddragon::Client;
     ///
     /// let api = Client::new("./cache").unwrap();
-    /// let champion = api.champion("MonkeyKing").unwrap();
-    /// let sprite = api.sprite_of(&champion).unwrap();
+    /// let downloaded = api.is_cached("championFull.json");
     /// ```
-    #[cfg(feature = "image")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "image")))]
-    pub fn sprite_of<T: HasImage>(&self, item: &T) -> Result<DynamicImage, ClientError> {
-        self.get_image(self.base_url.join(&format!(
-            "/cdn/{}/img/{}",
-            &self.version,
-            item.sprite_path()
-        ))?)
+    pub fn is_cached(&self, endpoint: &str) -> bool {
+        let Some(cache) = &self.cache else {
+            return false;
+        };
+        self.get_data_url()
+            .and_then(|url| url.join(endpoint))
+            .is_ok_and(|url| cache.contains(url.as_str()))
     }
-}
 
-#[cfg(test)]
-mod test {
-    use super::*;
-    use mockito::{Server, ServerGuard};
+    /// Fetches and caches dataset files, e.x. `item.json`, for the current
+    /// version up front, so later requests for them are served from the cache.
+    /// Files that are already [cached](Client::is_cached) are skipped, and up
+    /// to the configured [concurrency](ClientBuilder::concurrency) requests are
+    /// sent at once. Every endpoint is attempted even if some fail; failures
+    /// are reported together in [ClientError::Prewarm].
+    ///
+    /// ```no_run
+    /// use ddragon::Client;
+    ///
+    /// let api = Client::new("./cache").unwrap();
+    /// api.prewarm(&["champion.json", "item.json", "summoner.json"]).unwrap();
+    /// ```
+    pub fn prewarm(&self, endpoints: &[&str]) -> Result<(), ClientError> {
+        let data_url = self.get_data_url()?;
+        let pending: Vec<_> =
+            endpoints.iter().copied().filter(|endpoint| !self.is_cached(endpoint)).collect();
 
-    fn create_mock_client() -> (ServerGuard, String, Client) {
-        let server = Server::new();
-        let url = server.url();
-        (
-            server,
-            url.clone(),
-            Client {
-                agent: Agent::new_with_defaults(),
-                version: "0.0.0".to_owned(),
-                base_url: Url::parse(&url).unwrap(),
-            },
-        )
-    }
+        let mut failures = Vec::new();
+        for batch in pending.chunks(self.concurrency) {
+            let results = std::thread::scope(|scope| {
+                let handles: Vec<_> = batch
+                    .iter()
+                    .map(|endpoint| {
+                        let data_url = &data_url;
+                        scope.spawn(move || {
+                            let url = data_url.join(endpoint)?;
+                            get_bytes(
+                                &self.agent,
+                                &url,
+                                Some(&self.locale),
+                                self.local_preprocessor.as_ref(),
+                            )
+                        })
+                    })
+                    .collect();
+                handles
+                    .into_iter()
+                    .map(|handle| handle.join().expect("prewarm request thread panicked"))
+                    .collect::<Vec<_>>()
+            });
+            for (&endpoint, result) in batch.iter().zip(results) {
+                if let Err(error) = result {
+                    failures.push((endpoint.to_owned(), error));
+                }
+            }
+        }
 
-    mod create {
-        use super::*;
+        if failures.is_empty() {
+            Ok(())
+        } else {
+            Err(ClientError::Prewarm { failures })
+        }
+    }
+
+    /// Estimates how many bytes fetching every dataset for the current version
+    /// and locale would download, by summing the `Content-Length` of a `HEAD`
+    /// request for each file. Up to the configured
+    /// [concurrency](ClientBuilder::concurrency) requests are sent at once.
+    /// Files the server doesn't report a length for count as zero, and images
+    /// aren't included.
+    ///
+    /// ```no_run
+    /// use ddragon::Client;
+    ///
+    /// let api = Client::new("./cache").unwrap();
+    /// let size = api.estimate_patch_size().unwrap();
+    /// println!("This will download ~{} MB", size / 1_000_000);
+    /// ```
+    pub fn estimate_patch_size(&self) -> Result<u64, ClientError> {
+        let data_url = self.get_data_url()?;
+        let urls =
+            DATA_FILES.iter().map(|file| data_url.join(file)).collect::<Result<Vec<_>, _>>()?;
+
+        let mut total = 0;
+        for batch in urls.chunks(self.concurrency) {
+            let sizes = std::thread::scope(|scope| {
+                let handles: Vec<_> =
+                    batch.iter().map(|url| scope.spawn(move || self.content_length(url))).collect();
+                handles
+                    .into_iter()
+                    .map(|handle| handle.join().expect("size request thread panicked"))
+                    .collect::<Vec<_>>()
+            });
+            for size in sizes {
+                total += size?;
+            }
+        }
+        Ok(total)
+    }
+
+    fn content_length(&self, url: &Url) -> Result<u64, ClientError> {
+        if url.scheme() == "file" {
+            let metadata = std::fs::metadata(local_file_path(url)?);
+            return Ok(metadata.map_err(ClientError::LocalFile)?.len());
+        }
+
+        let response = self
+            .agent
+            .head(url.as_str())
+            .header("Accept-Language", accept_language(&self.locale))
+            .call()
+            .map_err(Box::new)?;
+        Ok(response
+            .headers()
+            .get("Content-Length")
+            .and_then(|length| length.to_str().ok()?.parse().ok())
+            .unwrap_or(0))
+    }
+
+    /// Returns how long ago a dataset file, e.x. `item.json`, was cached for
+    /// the current version. `None` when the file isn't cached, no cache is
+    /// configured, or the cache doesn't track when data was written.
+    ///
+    /// ```no_run
+    /// use ddragon::Client;
+    ///
+    /// let api = Client::new("./cache").unwrap();
+    /// if let Some(age) = api.cache_age("item.json") {
+    ///     println!("Item data is {} days old", age.as_secs() / 86400);
+    /// }
+    /// ```
+    pub fn cache_age(&self, endpoint: &str) -> Option<Duration> {
+        let url = self.get_data_url().and_then(|url| url.join(endpoint)).ok()?;
+        let written_at = self.cache.as_ref()?.written_at(url.as_str())?;
+        Some(SystemTime::now().duration_since(written_at).unwrap_or_default())
+    }
+
+    fn get_data_url(&self) -> Result<Cow<'_, Url>, url::ParseError> {
+        // The version can be changed after the client is built, so the cached
+        // URL is only reused while it still points at the current version.
+        if self.data_url.path_segments().and_then(|mut segments| segments.nth_back(3))
+            == Some(self.version.as_str())
+        {
+            Ok(Cow::Borrowed(&self.data_url))
+        } else {
+            data_url_for(&self.base_url, &self.version, &self.locale).map(Cow::Owned)
+        }
+    }
+
+    fn get_data<T: DeserializeOwned>(&self, endpoint: &str) -> Result<T, ClientError> {
+        let joined_url = self.get_data_url()?.join(endpoint)?;
+        self.get_localized_json(&joined_url, endpoint, &self.version, &self.locale)
+    }
+
+    fn get_localized_json<T: DeserializeOwned>(
+        &self,
+        url: &Url,
+        endpoint: &str,
+        version: &str,
+        locale: &str,
+    ) -> Result<T, ClientError> {
+        let body = get_bytes(&self.agent, url, Some(locale), self.local_preprocessor.as_ref())?;
+        self.check_data(&body, endpoint, version, locale)
+    }
+
+    /// Parses a dataset that was downloaded some other way with the same
+    /// checks and locale fallback as
+    /// [get_localized_json](Self::get_localized_json).
+    fn parse_checked<T: DeserializeOwned>(
+        &self,
+        body: &[u8],
+        endpoint: &str,
+    ) -> Result<T, ClientError> {
+        self.check_data(body, endpoint, &self.version, &self.locale)
+    }
+
+    fn locale_fallback_for(&self, locale: &str) -> Option<&str> {
+        self.locale_fallback.as_deref().filter(|fallback| *fallback != locale)
+    }
+
+    fn check_data<T: DeserializeOwned>(
+        &self,
+        body: &[u8],
+        endpoint: &str,
+        version: &str,
+        locale: &str,
+    ) -> Result<T, ClientError> {
+        let fallback = self.locale_fallback_for(locale);
+        // Parsing into a `Value` first is slower, so it's only done when the
+        // data has to be looked at before it's parsed into a model.
+        if fallback.is_none() && self.empty_datasets == EmptyDatasets::Allow {
+            return serde_json::from_slice(body).map_err(|e| ClientError::json(e, body));
+        }
+
+        let mut data: Value =
+            serde_json::from_slice(body).map_err(|e| ClientError::json(e, body))?;
+        check_empty_dataset(&data, endpoint, self.empty_datasets)?;
+        if let Some(fallback) = fallback.filter(|_| has_missing_names(&data)) {
+            let fallback_url = data_url_for(&self.base_url, version, fallback)?.join(endpoint)?;
+            let fallback_data: Value = get_json(
+                &self.agent,
+                &fallback_url,
+                Some(fallback),
+                self.local_preprocessor.as_ref(),
+            )?;
+            fill_missing_names(&mut data, &fallback_data);
+        }
+        T::deserialize(data).map_err(|e| {
+            // A `Value` has no positions, so the body is parsed again to find
+            // where it went wrong. Filling in names doesn't change the shape
+            // of the data, so this is expected to fail the same way.
+            match serde_json::from_slice::<T>(body) {
+                Err(located) => ClientError::json(located, body),
+                Ok(_) => ClientError::json(e, &[]),
+            }
+        })
+    }
+
+    fn get_data_with_progress<T: DeserializeOwned>(
+        &self,
+        endpoint: &str,
+        mut progress: impl FnMut(u64, Option<u64>),
+    ) -> Result<T, ClientError> {
+        let joined_url = self.get_data_url()?.join(endpoint)?;
+        if joined_url.scheme() == "file" {
+            let bytes = read_local_data(&joined_url, self.local_preprocessor.as_ref())?;
+            progress(bytes.len() as u64, Some(bytes.len() as u64));
+            return self.parse_checked(&bytes, endpoint);
+        }
+
+        let response = self
+            .agent
+            .get(joined_url.as_str())
+            .header("Accept-Language", accept_language(&self.locale))
+            .call()
+            .map_err(Box::new)?;
+        let total = response.body().content_length();
+        let mut reader =
+            ProgressReader { inner: response.into_body().into_reader(), read: 0, total, progress };
+
+        let mut body = Vec::new();
+        reader.read_to_end(&mut body)?;
+
+        self.parse_checked(&body, endpoint)
+    }
+
+    /// Fetches any dataset file, e.x. `item.json`, with the locale and version
+    /// optionally overridden by `options`. Unset options fall back to the
+    /// client's own locale and version.
+    ///
+    /// ```no_run
+    /// use ddragon::{models::Items, Client, FetchOptions};
+    ///
+    /// let api = Client::new("./cache").unwrap();
+    /// let options = FetchOptions::new().locale("ko_KR").version("13.1.1");
+    /// let items: Items = api.get_data_with("item.json", &options).unwrap();
+    /// ```
+    pub fn get_data_with<T: DeserializeOwned>(
+        &self,
+        endpoint: &str,
+        options: &FetchOptions,
+    ) -> Result<T, ClientError> {
+        let version = options.version.as_deref().unwrap_or(&self.version);
+        let locale = options.locale.as_deref().unwrap_or(&self.locale);
+        let joined_url = data_url_for(&self.base_url, version, locale)?.join(endpoint)?;
+        self.get_localized_json(&joined_url, endpoint, version, locale)
+    }
+
+    /// Fetches a dataset file, e.x. `item.json`, like [Client::get_data_with]
+    /// with no overrides, and reports how many bytes were transferred, whether
+    /// they came from the cache, and how long the fetch took.
+    ///
+    /// The stats only cover `endpoint` itself, not any data fetched from the
+    /// [fallback locale](ClientBuilder::locale_fallback) to fill in names.
+    ///
+    /// ```no_run
+    /// use ddragon::{models::Items, Client};
+    ///
+    /// let api = Client::new("./cache").unwrap();
+    /// let (items, stats) = api.fetch_with_stats::<Items>("item.json").unwrap();
+    /// println!("{} bytes in {:?}", stats.bytes, stats.duration);
+    /// ```
+    pub fn fetch_with_stats<T: DeserializeOwned>(
+        &self,
+        endpoint: &str,
+    ) -> Result<(T, FetchStats), ClientError> {
+        let joined_url = self.get_data_url()?.join(endpoint)?;
+        let started = Instant::now();
+        let (body, from_cache) = fetch_bytes(
+            &self.agent,
+            &joined_url,
+            Some(&self.locale),
+            self.local_preprocessor.as_ref(),
+        )?;
+        let stats =
+            FetchStats { bytes: body.len() as u64, from_cache, duration: started.elapsed() };
+        let data = self.parse_checked(&body, endpoint)?;
+        Ok((data, stats))
+    }
+
+    /// Fetches any JSON file on the ddragon host, e.x. `cdn/languages.json`,
+    /// with `path` joined directly onto the base URL rather than the data
+    /// directory of a version and locale.
+    ///
+    /// ```no_run
+    /// use ddragon::Client;
+    ///
+    /// let api = Client::new("./cache").unwrap();
+    /// let languages = api.get_cdn("cdn/languages.json").unwrap();
+    /// ```
+    pub fn get_cdn(&self, path: &str) -> Result<Value, ClientError> {
+        let joined_url = self.base_url.join(path)?;
+        get_json(&self.agent, &joined_url, None, self.local_preprocessor.as_ref())
+    }
+
+    create_endpoint!(challenges, "challenge", "challenges", Challenges);
+    create_endpoint!(champions, "champion", "champion", Champions);
+    create_endpoint!(champions_full, "complete champion", "championFull", ChampionsFull);
+    create_endpoint!(items, "item", "item", Items);
+    create_endpoint!(maps, "map", "map", Maps);
+    create_endpoint!(mission_assets, "mission asset", "mission-assets", MissionAssets);
+    create_endpoint!(profile_icons, "profile icon", "profileicon", ProfileIcons);
+    create_endpoint!(runes, "rune", "runesReforged", Runes);
+    create_endpoint!(spell_buffs, "spell buff", "spellbuffs", SpellBuffs);
+    create_endpoint!(stickers, "sticker (emote)", "sticker", Stickers);
+    create_endpoint!(summoner_spells, "summoner_spells", "summoner", SummonerSpells);
+    create_endpoint!(translations, "translation", "language", Translations);
+    create_endpoint!(tft_arenas, "TFT arena", "tft-arena", Arenas);
+    create_endpoint!(tft_augments, "TFT augment", "tft-augments", Augments);
+    create_endpoint!(tft_champions, "TFT champion", "tft-champion", tft::Champions);
+    create_endpoint!(tft_hero_augments, "TFT hero augment", "tft-hero-augments", HeroAugments);
+    create_endpoint!(tft_items, "TFT item", "tft-item", tft::Items);
+    create_endpoint!(tft_queues, "TFT queue", "tft-queues", Queues);
+    create_endpoint!(tft_regalia, "TFT regalia", "tft-regalia", Regalia);
+    create_endpoint!(tft_tacticians, "TFT tactician", "tft-tactician", Tacticians);
+    create_endpoint!(tft_traits, "TFT trait", "tft-trait", Traits);
+
+    /// Returns complete champion data, like [champions_full](Self::champions_full),
+    /// while calling `progress` with the number of bytes read so far and the
+    /// total size of the response, if the server reported one. This dataset is
+    /// several megabytes, so this is useful for showing a progress bar.
+    ///
+    /// ```no_run
+    /// use ddragon::Client;
+    ///
+    /// let api = Client::new("./cache").unwrap();
+    /// let champions_full = api
+    ///     .champions_full_with_progress(|read, total| println!("{read}/{total:?} bytes"))
+    ///     .unwrap();
+    /// ```
+    pub fn champions_full_with_progress(
+        &self,
+        progress: impl FnMut(u64, Option<u64>),
+    ) -> Result<ChampionsFull, ClientError> {
+        self.get_data_with_progress("./championFull.json", progress)
+    }
+
+    /// Returns data for a single champion. The champion's name or numeric key
+    /// should not be used here -- this should be the key property on the
+    /// [Champion] struct. This is usually the name, but differs in a bunch of
+    /// cases (e.x. Wukong's key is MonkeyKing).
+    ///
+    /// ```no_run
+    /// use ddragon::Client;
+    ///
+    /// let api = Client::new("./cache").unwrap();
+    /// let wukong = api.champion("MonkeyKing").unwrap();
+    /// ```
+    pub fn champion(&self, key: &str) -> Result<Champion, ClientError> {
+        self.get_data::<ChampionWrapper>(&format!("./champion/{key}.json"))?
+            .data
+            .get(key)
+            .cloned()
+            .ok_or(ClientError::NoChampionData)
+    }
+
+    /// Returns an iterator that fetches the full data for every champion. The
+    /// champion list is fetched up front, but each champion's data is only
+    /// requested as the iterator is advanced, in batches of up to the
+    /// configured [concurrency](ClientBuilder::concurrency) fetched in
+    /// parallel. Results are yielded in a stable order regardless, so callers
+    /// can process champions incrementally and stop early on failure.
+    ///
+    /// ```no_run
+    /// use ddragon::Client;
+    ///
+    /// let api = Client::new("./cache").unwrap();
+    /// for champion in api.champions_full_individually().unwrap() {
+    ///     println!("{}", champion.unwrap().name);
+    /// }
+    /// ```
+    pub fn champions_full_individually(
+        &self,
+    ) -> Result<impl Iterator<Item = Result<Champion, ClientError>> + '_, ClientError> {
+        let mut keys: Vec<String> = self.champions()?.data.into_keys().collect();
+        keys.sort();
+        let batches: Vec<Vec<String>> =
+            keys.chunks(self.concurrency).map(|batch| batch.to_vec()).collect();
+
+        Ok(batches.into_iter().flat_map(move |batch| {
+            std::thread::scope(|scope| {
+                let handles: Vec<_> =
+                    batch.iter().map(|key| scope.spawn(move || self.champion(key))).collect();
+                handles
+                    .into_iter()
+                    .map(|handle| handle.join().expect("champion fetch thread panicked"))
+                    .collect::<Vec<_>>()
+            })
+        }))
+    }
+
+    /// Returns the URL of a champion's tile art, the small square crop of
+    /// their default splash used in draft and loadout screens. Unlike most
+    /// images, tiles are not versioned. As with [champion](Self::champion),
+    /// this should be the champion's id (e.x. MonkeyKing for Wukong).
+    ///
+    /// ```no_run
+    /// use ddragon::Client;
+    ///
+    /// let api = Client::new("./cache").unwrap();
+    /// let url = api.champion_tile_url("MonkeyKing").unwrap();
+    /// ```
+    pub fn champion_tile_url(&self, champion_id: &str) -> Result<Url, ClientError> {
+        Ok(self.base_url.join(&format!("cdn/img/champion/tiles/{champion_id}_0.jpg"))?)
+    }
+
+    /// Returns the URL of a champion's splash art for the given skin number,
+    /// without checking that the skin exists. As with
+    /// [champion_tile_url](Self::champion_tile_url), splashes are not
+    /// versioned and `champion_id` should be the champion's id.
+    ///
+    /// ```no_run
+    /// use ddragon::Client;
+    ///
+    /// let api = Client::new("./cache").unwrap();
+    /// let url = api.splash_art_url("MonkeyKing", 0).unwrap();
+    /// ```
+    pub fn splash_art_url(&self, champion_id: &str, skin_num: i64) -> Result<Url, ClientError> {
+        Ok(self.base_url.join(&format!("cdn/img/champion/splash/{champion_id}_{skin_num}.jpg"))?)
+    }
+
+    /// Like [splash_art_url](Self::splash_art_url), but fetches the
+    /// champion's data first and returns `None` if it has no skin with that
+    /// number, so a missing splash is never requested.
+    ///
+    /// ```no_run
+    /// use ddragon::Client;
+    ///
+    /// let api = Client::new("./cache").unwrap();
+    /// if let Some(url) = api.splash_art_url_checked("MonkeyKing", 1).unwrap() {
+    ///     println!("{}", url);
+    /// }
+    /// ```
+    pub fn splash_art_url_checked(
+        &self,
+        champion_id: &str,
+        skin_num: i64,
+    ) -> Result<Option<Url>, ClientError> {
+        let champion = self.champion(champion_id)?;
+        if !champion.skins.iter().any(|skin| skin.num == skin_num) {
+            return Ok(None);
+        }
+        self.splash_art_url(champion_id, skin_num).map(Some)
+    }
+
+    /// Returns the URL of each of a challenge's tier icons, lowest tier first.
+    /// Like champion tiles, challenge icons are not versioned.
+    ///
+    /// ```no_run
+    /// use ddragon::Client;
+    ///
+    /// let api = Client::new("./cache").unwrap();
+    /// let challenges = api.challenges().unwrap();
+    /// for (tier, url) in api.challenge_icon_urls(&challenges[0]).unwrap() {
+    ///     println!("{:?}: {}", tier, url);
+    /// }
+    /// ```
+    pub fn challenge_icon_urls(
+        &self,
+        challenge: &Challenge,
+    ) -> Result<Vec<(ChallengeTier, Url)>, ClientError> {
+        challenge
+            .level_to_icon_path
+            .iter()
+            .map(|(tier, path)| Ok((tier, challenge_icon_url(&self.base_url, path)?)))
+            .collect()
+    }
+
+    /// Returns the URL of the full (non-sprite) image for anything that has
+    /// one, without downloading it.
+    ///
+    /// ```no_run
+    /// use ddragon::Client;
+    ///
+    /// let api = Client::new("./cache").unwrap();
+    /// let champion = api.champion("MonkeyKing").unwrap();
+    /// let url = api.image_url(&champion.summary()).unwrap();
+    /// ```
+    pub fn image_url<T: HasImage>(&self, item: &T) -> Result<Url, ClientError> {
+        Ok(self.base_url.join(&format!("cdn/{}/img/{}", &self.version, item.image_path()))?)
+    }
+
+    /// Returns the URL of a champion's passive icon.
+    ///
+    /// ```no_run
+    /// use ddragon::Client;
+    ///
+    /// let api = Client::new("./cache").unwrap();
+    /// let champion = api.champion("MonkeyKing").unwrap();
+    /// let url = api.passive_image_url(&champion).unwrap();
+    /// ```
+    pub fn passive_image_url(&self, champion: &Champion) -> Result<Url, ClientError> {
+        self.image_url(champion.passive())
+    }
+
+    /// Returns the URL of an ability's icon, under `img/spell/`.
+    ///
+    /// ```no_run
+    /// use ddragon::{models::champion::SpellSlot, Client};
+    ///
+    /// let api = Client::new("./cache").unwrap();
+    /// let champion = api.champion("MonkeyKing").unwrap();
+    /// let url = api.spell_icon_url(champion.spell(SpellSlot::Q).unwrap()).unwrap();
+    /// ```
+    pub fn spell_icon_url(&self, spell: &Spell) -> Result<Url, ClientError> {
+        self.image_url(spell)
+    }
+
+    /// Returns the raw bytes of the full (non-sprite) image for anything that
+    /// has one, e.x. the PNG of an item's icon. The directory is taken from the
+    /// image's `group`, and the bytes are cached under the resolved URL just
+    /// like JSON data.
+    ///
+    /// ```no_run
+    /// use ddragon::Client;
+    ///
+    /// let api = Client::new("./cache").unwrap();
+    /// let champion = api.champion("MonkeyKing").unwrap();
+    /// let png = api.fetch_image(&champion.image).unwrap();
+    /// ```
+    pub fn fetch_image<T: HasImage>(&self, item: &T) -> Result<Vec<u8>, ClientError> {
+        get_bytes(&self.agent, &self.image_url(item)?, None, None)
+    }
+
+    #[cfg(feature = "image")]
+    fn get_image(&self, path: Url) -> Result<DynamicImage, ClientError> {
+        load_from_memory(&get_bytes(&self.agent, &path, None, None)?).map_err(|e| e.into())
+    }
+
+    /// Returns an [image::DynamicImage].
+    ///
+    /// ```no_run
+    /// use ddragon::Client;
+    ///
+    /// let api = Client::new("./cache").unwrap();
+    /// let champion = api.champion("MonkeyKing").unwrap();
+    /// let image = api.image_of(&champion).unwrap();
+    /// ```
+    #[cfg(feature = "image")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "image")))]
+    pub fn image_of<T: HasImage>(&self, item: &T) -> Result<DynamicImage, ClientError> {
+        self.get_image(self.base_url.join(&format!(
+            "cdn/{}/img/{}",
+            &self.version,
+            item.image_path()
+        ))?)
+    }
+
+    /// Returns an [image::DynamicImage].
+    ///
+    /// Keep in mind that this is a spritesheet image. You will have to cut out
+    /// the appropriate piece using the information on the [Image](crate::models::shared::Image).
+    ///
+    /// ```no_run
+    /// use ddragon::Client;
+    ///
+    /// let api = Client::new("./cache").unwrap();
+    /// let champion = api.champion("MonkeyKing").unwrap();
+    /// let sprite = api.sprite_of(&champion).unwrap();
+    /// ```
+    #[cfg(feature = "image")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "image")))]
+    pub fn sprite_of<T: HasImage>(&self, item: &T) -> Result<DynamicImage, ClientError> {
+        self.get_image(self.base_url.join(&format!(
+            "cdn/{}/img/{}",
+            &self.version,
+            item.sprite_path()
+        ))?)
+    }
+
+    /// Returns the PNG-encoded bytes of a single icon, cut out of the sprite
+    /// sheet it belongs to. Sprite sheets are kept in memory once downloaded,
+    /// so cropping other icons from the same sheet does not fetch it again.
+    ///
+    /// ```no_run
+    /// use ddragon::Client;
+    ///
+    /// let api = Client::new("./cache").unwrap();
+    /// let champion = api.champion("MonkeyKing").unwrap();
+    /// let icon = api.crop_icon(&champion.image).unwrap();
+    /// ```
+    #[cfg(feature = "image")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "image")))]
+    pub fn crop_icon(&self, image: &Image) -> Result<Vec<u8>, ClientError> {
+        let sprite_url =
+            self.base_url.join(&format!("cdn/{}/img/{}", &self.version, image.sprite_path()))?;
+        let cache_key = sprite_url.to_string();
+
+        let cached_icon = self
+            .sprites
+            .lock()
+            .ok()
+            .and_then(|sprites| sprites.get(&cache_key).map(|sprite| image.crop_from(sprite)));
+        let icon = match cached_icon {
+            Some(icon) => icon,
+            None => {
+                let sprite = self.get_image(sprite_url)?;
+                let icon = image.crop_from(&sprite);
+                if let Ok(mut sprites) = self.sprites.lock() {
+                    sprites.insert(cache_key, sprite);
+                }
+                icon
+            }
+        };
+
+        let mut png_bytes = Vec::new();
+        icon.write_to(&mut Cursor::new(&mut png_bytes), ImageOutputFormat::Png)?;
+        Ok(png_bytes)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use mockito::{Server, ServerGuard};
+
+    fn create_mock_client() -> (ServerGuard, String, Client) {
+        let server = Server::new();
+        let url = server.url();
+        (
+            server,
+            url.clone(),
+            Client {
+                agent: Agent::new_with_defaults(),
+                cache: None,
+                version: "0.0.0".to_owned(),
+                locale: "en_US".to_owned(),
+                locale_fallback: None,
+                empty_datasets: EmptyDatasets::Allow,
+                concurrency: 8,
+                versions_ttl: Duration::from_secs(5 * 60),
+                local_preprocessor: None,
+                base_url: Url::parse(&url).unwrap(),
+                data_url: Url::parse(&format!("{}/cdn/0.0.0/data/en_US/", url)).unwrap(),
+                #[cfg(feature = "image")]
+                sprites: Default::default(),
+            },
+        )
+    }
+
+    fn mock_champion(id: &str) -> Champion {
+        let image = |full: String, group: &str| crate::models::shared::Image {
+            full,
+            sprite: "sprite0.png".to_owned(),
+            group: group.to_owned(),
+            x: 0,
+            y: 0,
+            w: 48,
+            h: 48,
+        };
+        Champion {
+            id: id.to_owned(),
+            key: "0".to_owned(),
+            name: id.to_owned(),
+            title: String::new(),
+            image: image(format!("{id}.png"), "champion"),
+            skins: vec![],
+            lore: String::new(),
+            blurb: String::new(),
+            allytips: vec![],
+            enemytips: vec![],
+            tags: vec![],
+            partype: "Mana".to_owned(),
+            info: crate::models::champion::Info { attack: 0, defense: 0, magic: 0, difficulty: 0 },
+            stats: Default::default(),
+            spells: vec![],
+            passive: crate::models::champion::Passive {
+                name: String::new(),
+                description: String::new(),
+                image: image(format!("{id}Passive.png"), "passive"),
+            },
+            recommended: vec![],
+        }
+    }
+
+    fn mock_champion_data(champions: &[Champion], short: bool) -> String {
+        let data: serde_json::Map<String, serde_json::Value> = champions
+            .iter()
+            .map(|champion| {
+                let mut value = serde_json::to_value(champion).unwrap();
+                if short {
+                    value["version"] = "0.0.0".into();
+                }
+                (champion.id.clone(), value)
+            })
+            .collect();
+        serde_json::json!({ "format": "standAloneComplex", "version": "0.0.0", "data": data })
+            .to_string()
+    }
+
+    mod create {
+        use super::*;
+
+        #[test]
+        fn with_base_url_and_version_fetches_from_base_url() {
+            let mut server = Server::new();
+            let _mock = server
+                .mock("GET", "/cdn/1.1.1/data/en_US/data.json")
+                .with_status(200)
+                .with_header("Content-Type", "application/json")
+                .with_body(r#"["value"]"#)
+                .create();
+
+            let client = Client::with_base_url_and_version(&server.url(), "1.1.1").unwrap();
+
+            assert_eq!(client.version, "1.1.1");
+            assert_eq!(client.get_data::<Vec<String>>("data.json").unwrap(), ["value"]);
+        }
+
+        #[test]
+        fn with_existing_version_skips_version_request() {
+            let client =
+                Client::with_existing_version(Agent::new_with_defaults(), "1.1.1").unwrap();
+
+            assert_eq!(client.version, "1.1.1");
+        }
+
+        #[test]
+        fn cached_versions_are_reused_and_used_offline() {
+            let mut server = Server::new();
+            let url = server.url();
+            let dir = std::env::temp_dir().join("ddragon-cached-versions-sync");
+            let _ = std::fs::remove_dir_all(&dir);
+            let builder = || ClientBuilder::new().server(&url).cache(dir.to_str().unwrap());
+
+            let mock = server
+                .mock("GET", "/api/versions.json")
+                .with_status(200)
+                .with_header("Content-Type", "application/json")
+                .with_body(r#"["1.1.1", "1.1.0"]"#)
+                .expect(1)
+                .create();
+            assert_eq!(builder().build().unwrap().version, "1.1.1");
+            assert_eq!(builder().build().unwrap().versions().unwrap(), ["1.1.1", "1.1.0"]);
+            mock.assert();
+
+            let _mock = server.mock("GET", "/api/versions.json").with_status(500).create();
+            let client = builder().versions_ttl(Duration::ZERO).build().unwrap();
+            assert_eq!(client.version, "1.1.1");
+        }
+
+        #[test]
+        fn result_ok_if_at_least_one_version() {
+            let mut server = Server::new();
+            let _mock = server
+                .mock("GET", "/api/versions.json")
+                .with_status(200)
+                .with_header("Content-Type", "application/json")
+                .with_body(r#"["0.0.0"]"#)
+                .create();
+
+            let maybe_client = ClientBuilder::new().server(&server.url()).build();
+
+            assert!(maybe_client.is_ok());
+            assert_eq!(maybe_client.unwrap().version, "0.0.0");
+        }
+
+        #[test]
+        fn result_ok_first_version_in_list() {
+            let mut server = Server::new();
+            let _mock = server
+                .mock("GET", "/api/versions.json")
+                .with_status(200)
+                .with_header("Content-Type", "application/json")
+                .with_body(r#"["0.0.0", "1.1.1", "2.2.2"]"#)
+                .create();
+
+            let maybe_client = ClientBuilder::new().server(&server.url()).build();
+
+            assert!(maybe_client.is_ok());
+            assert_eq!(maybe_client.unwrap().version, "0.0.0");
+        }
+
+        #[test]
+        fn result_err_unexpected_if_versions_are_html() {
+            let mut server = Server::new();
+            let _mock = server
+                .mock("GET", "/api/versions.json")
+                .with_status(200)
+                .with_header("Content-Type", "text/html")
+                .with_body("<html><body>Service Unavailable</body></html>")
+                .create();
+
+            match ClientBuilder::new().server(&server.url()).build() {
+                Err(ClientError::UnexpectedVersions { status, snippet }) => {
+                    assert_eq!(status, Some(200));
+                    assert!(snippet.starts_with("<html>"));
+                }
+                Err(other) => panic!("expected UnexpectedVersions, got {other:?}"),
+                Ok(_) => panic!("expected UnexpectedVersions, got a client"),
+            }
+        }
+
+        #[test]
+        fn result_err_unexpected_if_versions_unavailable() {
+            let mut server = Server::new();
+            let _mock = server
+                .mock("GET", "/api/versions.json")
+                .with_status(503)
+                .with_header("Content-Type", "text/html")
+                .with_body("<html><body>Service Unavailable</body></html>")
+                .create();
+
+            match ClientBuilder::new().server(&server.url()).build() {
+                Err(ClientError::UnexpectedVersions { status, snippet }) => {
+                    assert_eq!(status, Some(503));
+                    assert!(snippet.starts_with("<html>"));
+                }
+                Err(other) => panic!("expected UnexpectedVersions, got {other:?}"),
+                Ok(_) => panic!("expected UnexpectedVersions, got a client"),
+            }
+        }
+
+        #[test]
+        fn result_err_server_unavailable() {
+            assert!(ClientBuilder::new().server("https://a-very-fake.urltogoto").build().is_err());
+        }
+
+        #[test]
+        fn result_err_timeout_if_server_never_responds() {
+            let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+            let client = ClientBuilder::new()
+                .server(&format!("http://{}", listener.local_addr().unwrap()))
+                .version("0.0.0")
+                .timeout(Duration::from_millis(100))
+                .build()
+                .unwrap();
+
+            assert!(matches!(client.items(), Err(ClientError::Timeout)));
+        }
+
+        #[test]
+        fn result_ok_from_local_mirror() {
+            let dir = std::env::temp_dir().join("ddragon-local-mirror-sync");
+            std::fs::create_dir_all(dir.join("api")).unwrap();
+            std::fs::create_dir_all(dir.join("cdn/0.0.0/data/en_US")).unwrap();
+            std::fs::write(dir.join("api/versions.json"), r#"["0.0.0"]"#).unwrap();
+            std::fs::write(dir.join("cdn/0.0.0/data/en_US/data.json"), r#"{"hello":"world"}"#)
+                .unwrap();
+
+            let client = ClientBuilder::new().local_dir(dir.to_str().unwrap()).build().unwrap();
+            let data = client
+                .get_data::<std::collections::HashMap<String, String>>("./data.json")
+                .unwrap();
+
+            assert_eq!(client.version, "0.0.0");
+            assert_eq!(data.get("hello"), Some(&"world".to_owned()));
+        }
+
+        #[test]
+        fn local_preprocessor_applies_to_local_files() {
+            let dir = std::env::temp_dir().join("ddragon-local-preprocessor-sync");
+            std::fs::create_dir_all(dir.join("api")).unwrap();
+            std::fs::create_dir_all(dir.join("cdn/0.0.0/data/en_US")).unwrap();
+            std::fs::write(dir.join("api/versions.json"), r#"["0.0.0"]"#).unwrap();
+            std::fs::write(dir.join("cdn/0.0.0/data/en_US/data.json"), "\u{feff}[\"value\"]")
+                .unwrap();
+
+            let strict = ClientBuilder::new().local_dir(dir.to_str().unwrap()).build().unwrap();
+            assert!(strict.get_data::<Vec<String>>("./data.json").is_err());
+
+            let relaxed = ClientBuilder::new()
+                .local_dir(dir.to_str().unwrap())
+                .local_preprocessor(|bytes| match bytes.strip_prefix("\u{feff}".as_bytes()) {
+                    Some(stripped) => stripped.to_vec(),
+                    None => bytes,
+                })
+                .build()
+                .unwrap();
+            assert_eq!(relaxed.get_data::<Vec<String>>("./data.json").unwrap(), vec!["value"]);
+        }
+
+        #[test]
+        fn from_vars_reads_version_cache_dir_and_locale() {
+            let vars = std::collections::HashMap::from([
+                ("DDRAGON_VERSION", "1.2.3"),
+                ("DDRAGON_CACHE_DIR", "./ddragon-from-env"),
+                ("DDRAGON_LOCALE", "ko_KR"),
+            ]);
+
+            let builder =
+                ClientBuilder::from_vars(|name| vars.get(name).map(|value| value.to_string()));
+
+            assert_eq!(builder.version.as_deref(), Some("1.2.3"));
+            assert_eq!(builder.cache.as_deref(), Some("./ddragon-from-env"));
+            assert_eq!(builder.locale, "ko_KR");
+
+            let builder = ClientBuilder::from_vars(|_| None);
+            assert_eq!(builder.version, None);
+            assert_eq!(builder.cache, None);
+            assert_eq!(builder.locale, "en_US");
+        }
+
+        #[test]
+        fn result_err_no_versions_in_list() {
+            let mut server = Server::new();
+            let _mock = server
+                .mock("GET", "/api/versions.json")
+                .with_status(200)
+                .with_header("Content-Type", "application/json")
+                .with_body(r#"[]"#)
+                .create();
+
+            assert!(ClientBuilder::new().server(&server.url()).build().is_err());
+        }
+
+        #[test]
+        fn result_err_cannot_deserialize() {
+            let mut server = Server::new();
+            let _mock = server
+                .mock("GET", "/api/versions.json")
+                .with_status(200)
+                .with_body(r#"some non-deserializable content"#)
+                .create();
+
+            assert!(ClientBuilder::new().server(&server.url()).build().is_err());
+        }
+
+        #[test]
+        fn result_ok_manual_version() {
+            let mut server = Server::new();
+            let _mock = server
+                .mock("GET", "/api/versions.json")
+                .with_status(200)
+                .with_header("Content-Type", "application/json")
+                .with_body(r#"["0.0.0", "1.1.1", "2.2.2"]"#)
+                .create();
+
+            let maybe_client = ClientBuilder::new().server(&server.url()).version("3.3.3").build();
+
+            assert!(maybe_client.is_ok());
+            assert_eq!(maybe_client.unwrap().version, "3.3.3");
+        }
+    }
+
+    mod requests {
+        use super::*;
+
+        #[test]
+        fn health_check_ok_if_versions_listed() {
+            let (mut server, _url, client) = create_mock_client();
+            let _mock = server
+                .mock("GET", "/api/versions.json")
+                .with_status(200)
+                .with_header("Content-Type", "application/json")
+                .with_body(r#"["0.0.0"]"#)
+                .create();
+
+            assert!(client.health_check().is_ok());
+        }
+
+        #[test]
+        fn health_check_err_if_no_versions() {
+            let (mut server, _url, client) = create_mock_client();
+            let _mock = server
+                .mock("GET", "/api/versions.json")
+                .with_status(200)
+                .with_header("Content-Type", "application/json")
+                .with_body("[]")
+                .create();
+
+            assert!(matches!(client.health_check(), Err(ClientError::NoLatestVersion)));
+        }
+
+        #[test]
+        fn get_data_url_constructs_expected_baseurl() {
+            let (_server, url, client) = create_mock_client();
+            assert_eq!(
+                client.get_data_url().unwrap().as_str(),
+                format!("{}/cdn/0.0.0/data/en_US/", url)
+            );
+        }
+
+        #[test]
+        fn get_data_url_follows_version_changes() {
+            let (_server, url, mut client) = create_mock_client();
+            client.version = "1.1.1".to_owned();
+            assert_eq!(
+                client.get_data_url().unwrap().as_str(),
+                format!("{}/cdn/1.1.1/data/en_US/", url)
+            );
+        }
 
         #[test]
-        fn result_ok_if_at_least_one_version() {
-            let mut server = Server::new();
+        fn challenge_icon_urls_resolve_under_cdn_img() {
+            let (_server, url, client) = create_mock_client();
+            let challenge: Challenge = serde_json::from_value(serde_json::json!({
+                "id": 1,
+                "name": "",
+                "description": "",
+                "shortDescription": "",
+                "hasLeaderboard": false,
+                "levelToIconPath": {
+                    "IRON": "/challenges-images/1-IRON.png",
+                    "GOLD": "https://example.com/1-GOLD.png"
+                },
+                "thresholds": {}
+            }))
+            .unwrap();
+
+            let urls: Vec<(ChallengeTier, String)> = client
+                .challenge_icon_urls(&challenge)
+                .unwrap()
+                .into_iter()
+                .map(|(tier, url)| (tier, url.to_string()))
+                .collect();
+            assert_eq!(
+                urls,
+                [
+                    (ChallengeTier::Iron, format!("{}/cdn/img/challenges-images/1-IRON.png", url)),
+                    (ChallengeTier::Gold, "https://example.com/1-GOLD.png".to_owned()),
+                ]
+            );
+        }
+
+        #[test]
+        fn splash_art_url_checked_requires_the_skin() {
+            let (mut server, url, client) = create_mock_client();
+            let mut champion = mock_champion("MonkeyKing");
+            champion.skins = vec![crate::models::champion::Skin {
+                id: "62000".to_owned(),
+                num: 0,
+                name: "default".to_owned(),
+                chromas: false,
+            }];
             let _mock = server
-                .mock("GET", "/api/versions.json")
+                .mock("GET", "/cdn/0.0.0/data/en_US/champion/MonkeyKing.json")
                 .with_status(200)
                 .with_header("Content-Type", "application/json")
-                .with_body(r#"["0.0.0"]"#)
+                .with_body(mock_champion_data(&[champion], false))
                 .create();
 
-            let maybe_client = ClientBuilder::new().server(&server.url()).build();
+            assert_eq!(
+                client.splash_art_url_checked("MonkeyKing", 0).unwrap().unwrap().as_str(),
+                format!("{}/cdn/img/champion/splash/MonkeyKing_0.jpg", url)
+            );
+            assert_eq!(client.splash_art_url_checked("MonkeyKing", 7).unwrap(), None);
+        }
 
-            assert!(maybe_client.is_ok());
-            assert_eq!(maybe_client.unwrap().version, "0.0.0");
+        #[test]
+        fn champion_tile_url_is_unversioned() {
+            let (_server, url, client) = create_mock_client();
+            assert_eq!(
+                client.champion_tile_url("MonkeyKing").unwrap().as_str(),
+                format!("{}/cdn/img/champion/tiles/MonkeyKing_0.jpg", url)
+            );
         }
 
         #[test]
-        fn result_ok_first_version_in_list() {
-            let mut server = Server::new();
-            let _mock = server
-                .mock("GET", "/api/versions.json")
-                .with_status(200)
-                .with_header("Content-Type", "application/json")
-                .with_body(r#"["0.0.0", "1.1.1", "2.2.2"]"#)
-                .create();
+        fn passive_image_url_uses_passive_image() {
+            let (_server, url, client) = create_mock_client();
+            let champion = mock_champion("MonkeyKing");
 
-            let maybe_client = ClientBuilder::new().server(&server.url()).build();
+            assert_eq!(
+                client.passive_image_url(&champion).unwrap().as_str(),
+                format!("{}/cdn/0.0.0/img/passive/MonkeyKingPassive.png", url)
+            );
+        }
 
-            assert!(maybe_client.is_ok());
-            assert_eq!(maybe_client.unwrap().version, "0.0.0");
+        #[test]
+        fn spell_icon_url_uses_spell_image() {
+            let (_server, url, client) = create_mock_client();
+            let spell = crate::models::champion::test::spell(serde_json::json!({
+                "image": {
+                    "full": "MonkeyKingDoubleAttack.png",
+                    "sprite": "spell8.png",
+                    "group": "spell",
+                    "x": 0, "y": 0, "w": 48, "h": 48
+                }
+            }));
+
+            assert_eq!(
+                client.spell_icon_url(&spell).unwrap().as_str(),
+                format!("{}/cdn/0.0.0/img/spell/MonkeyKingDoubleAttack.png", url)
+            );
         }
 
         #[test]
-        fn result_err_server_unavailable() {
-            assert!(ClientBuilder::new().server("https://a-very-fake.urltogoto").build().is_err());
+        fn with_realm_adopts_cdn_and_version() {
+            let (_server, _url, client) = create_mock_client();
+            let realm: Realm = serde_json::from_value(serde_json::json!({
+                "n": {},
+                "v": "14.1.1",
+                "l": "en_GB",
+                "cdn": "https://cdn.example.com/cdn",
+                "dd": "14.1.2"
+            }))
+            .unwrap();
+            let client = client.with_realm(&realm).unwrap();
+
+            assert_eq!(client.version, "14.1.2");
+            assert_eq!(
+                client.get_data_url().unwrap().as_str(),
+                "https://cdn.example.com/cdn/14.1.2/data/en_US/"
+            );
+            assert_eq!(
+                client.image_url(&mock_champion("Ahri").summary()).unwrap().as_str(),
+                "https://cdn.example.com/cdn/14.1.2/img/champion/Ahri.png"
+            );
         }
 
         #[test]
-        fn result_err_no_versions_in_list() {
-            let mut server = Server::new();
+        fn fetch_image_downloads_and_caches_bytes() {
+            let (mut server, _url, client) = create_mock_client();
             let _mock = server
-                .mock("GET", "/api/versions.json")
+                .mock("GET", "/cdn/0.0.0/img/item/1001.png")
                 .with_status(200)
-                .with_header("Content-Type", "application/json")
-                .with_body(r#"[]"#)
+                .with_header("Content-Type", "image/png")
+                .with_body([0x89, b'P', b'N', b'G'])
+                .expect(1)
                 .create();
+            let dir = std::env::temp_dir().join("ddragon-fetch-image-sync");
+            let _ = std::fs::remove_dir_all(&dir);
+            let agent = Agent::config_builder()
+                .middleware(CacheMiddleware::new(dir.to_str().unwrap()))
+                .build()
+                .into();
+            let client = Client { agent, ..client };
 
-            assert!(ClientBuilder::new().server(&server.url()).build().is_err());
+            let image = crate::models::shared::Image {
+                full: "1001.png".to_owned(),
+                sprite: "item0.png".to_owned(),
+                group: "item".to_owned(),
+                x: 0,
+                y: 0,
+                w: 48,
+                h: 48,
+            };
+            assert_eq!(client.fetch_image(&image).unwrap(), [0x89, b'P', b'N', b'G']);
+            assert_eq!(client.fetch_image(&image).unwrap(), [0x89, b'P', b'N', b'G']);
+            _mock.assert();
         }
 
         #[test]
-        fn result_err_cannot_deserialize() {
-            let mut server = Server::new();
-            let _mock = server
-                .mock("GET", "/api/versions.json")
+        fn image_url_of_summary_is_square_portrait() {
+            let (_server, url, client) = create_mock_client();
+            let summary = mock_champion("Ahri").summary();
+
+            assert_eq!(
+                client.image_url(&summary).unwrap().as_str(),
+                format!("{}/cdn/0.0.0/img/champion/Ahri.png", url)
+            );
+        }
+
+        #[test]
+        fn champions_full_individually_fetches_each_champion() {
+            let (mut server, _url, client) = create_mock_client();
+            let champions = [mock_champion("MonkeyKing"), mock_champion("Ahri")];
+            let _list = server
+                .mock("GET", "/cdn/0.0.0/data/en_US/champion.json")
                 .with_status(200)
-                .with_body(r#"some non-deserializable content"#)
+                .with_header("Content-Type", "application/json")
+                .with_body(mock_champion_data(&champions, true))
                 .create();
+            let _details: Vec<_> = champions
+                .iter()
+                .map(|champion| {
+                    server
+                        .mock(
+                            "GET",
+                            &*format!("/cdn/0.0.0/data/en_US/champion/{}.json", champion.id),
+                        )
+                        .with_status(200)
+                        .with_header("Content-Type", "application/json")
+                        .with_body(mock_champion_data(std::slice::from_ref(champion), false))
+                        .create()
+                })
+                .collect();
 
-            assert!(ClientBuilder::new().server(&server.url()).build().is_err());
+            let fetched: Vec<Champion> =
+                client.champions_full_individually().unwrap().map(Result::unwrap).collect();
+            assert_eq!(fetched, vec![champions[1].clone(), champions[0].clone()]);
         }
 
         #[test]
-        fn result_ok_manual_version() {
-            let mut server = Server::new();
+        fn champions_full_with_progress_reports_bytes_read() {
+            let (mut server, _url, client) = create_mock_client();
+            let mut body: serde_json::Value =
+                serde_json::from_str(&mock_champion_data(&[mock_champion("Ahri")], false)).unwrap();
+            body["keys"] = serde_json::json!({ "0": "Ahri" });
+            let body = body.to_string();
             let _mock = server
-                .mock("GET", "/api/versions.json")
+                .mock("GET", "/cdn/0.0.0/data/en_US/championFull.json")
                 .with_status(200)
                 .with_header("Content-Type", "application/json")
-                .with_body(r#"["0.0.0", "1.1.1", "2.2.2"]"#)
+                .with_body(&body)
                 .create();
 
-            let maybe_client = ClientBuilder::new().server(&server.url()).version("3.3.3").build();
+            let mut reports = Vec::new();
+            let champions_full = client
+                .champions_full_with_progress(|read, total| reports.push((read, total)))
+                .unwrap();
 
-            assert!(maybe_client.is_ok());
-            assert_eq!(maybe_client.unwrap().version, "3.3.3");
+            assert_eq!(champions_full.len(), 1);
+            assert_eq!(reports.last(), Some(&(body.len() as u64, Some(body.len() as u64))));
         }
-    }
-
-    mod requests {
-        use super::*;
 
         #[test]
-        fn get_data_url_constructs_expected_baseurl() {
-            let (_server, url, client) = create_mock_client();
-            assert_eq!(
-                client.get_data_url().unwrap().as_str(),
-                format!("{}/cdn/0.0.0/data/en_US/", url)
-            );
+        fn champions_full_with_progress_checks_empty_datasets() {
+            let (mut server, _url, client) = create_mock_client();
+            let client = Client { empty_datasets: EmptyDatasets::Error, ..client };
+            let _mock = server
+                .mock("GET", "/cdn/0.0.0/data/en_US/championFull.json")
+                .with_status(200)
+                .with_header("Content-Type", "application/json")
+                .with_body(
+                    r#"{"type":"champion","format":"full","version":"0.0.0","data":{},"keys":{}}"#,
+                )
+                .create();
+
+            let rejected = client.champions_full_with_progress(|_, _| {});
+            assert!(matches!(rejected, Err(ClientError::EmptyDataset { .. })));
         }
 
         #[test]
@@ -445,6 +2059,25 @@ mod test {
             assert!(client.get_data::<String>("./data.json").is_err());
         }
 
+        #[test]
+        fn get_data_err_reports_json_position() {
+            let (mut server, _url, client) = create_mock_client();
+            let _mock = server
+                .mock("GET", "/cdn/0.0.0/data/en_US/data.json")
+                .with_status(200)
+                .with_header("Content-Type", "application/json")
+                .with_body("{\n  \"value\": oops\n}")
+                .create();
+
+            match client.get_data::<std::collections::HashMap<String, String>>("./data.json") {
+                Err(ClientError::Json { line, column, snippet, .. }) => {
+                    assert_eq!((line, column), (2, 12));
+                    assert!(snippet.contains("\"value\": oops"));
+                }
+                other => panic!("expected a JSON error, got {other:?}"),
+            }
+        }
+
         #[test]
         fn get_data_ok_deserializes_to_type() {
             let (mut server, _url, client) = create_mock_client();
@@ -460,5 +2093,336 @@ mod test {
                 vec!["value".to_owned()]
             );
         }
+
+        #[test]
+        fn verify_checksums_keeps_last_modified_for_revalidation() {
+            let mut server = Server::new();
+            let dir = std::env::temp_dir().join("ddragon-checksum-revalidate-sync");
+            let _ = std::fs::remove_dir_all(&dir);
+            let client = ClientBuilder::new()
+                .server(&server.url())
+                .version("0.0.0")
+                .cache(dir.to_str().unwrap())
+                .verify_checksums(true)
+                .revalidate(true)
+                .build()
+                .unwrap();
+
+            {
+                let _full = server
+                    .mock("GET", "/cdn/0.0.0/data/en_US/data.json")
+                    .with_status(200)
+                    .with_header("Content-Type", "application/json")
+                    .with_header("Content-MD5", "ilvfVP2SGN5DbA8E8AyJbw==")
+                    .with_header("Last-Modified", "Wed, 21 Oct 2015 07:28:00 GMT")
+                    .with_body(r#"["value"]"#)
+                    .create();
+                assert_eq!(client.get_data::<Vec<String>>("data.json").unwrap(), ["value"]);
+            }
+
+            let _not_modified = server
+                .mock("GET", "/cdn/0.0.0/data/en_US/data.json")
+                .match_header("If-Modified-Since", "Wed, 21 Oct 2015 07:28:00 GMT")
+                .with_status(304)
+                .expect(1)
+                .create();
+            assert_eq!(client.get_data::<Vec<String>>("data.json").unwrap(), ["value"]);
+            _not_modified.assert();
+        }
+
+        #[test]
+        fn verify_checksums_rejects_mismatched_body_without_caching() {
+            let mut server = Server::new();
+            let dir = std::env::temp_dir().join("ddragon-checksum-sync");
+            let _ = std::fs::remove_dir_all(&dir);
+            let client = ClientBuilder::new()
+                .server(&server.url())
+                .version("0.0.0")
+                .cache(dir.to_str().unwrap())
+                .verify_checksums(true)
+                .build()
+                .unwrap();
+            let _bad = server
+                .mock("GET", "/cdn/0.0.0/data/en_US/bad.json")
+                .with_status(200)
+                .with_header("Content-Type", "application/json")
+                .with_header("Content-MD5", "AAAAAAAAAAAAAAAAAAAAAA==")
+                .with_body(r#"["value"]"#)
+                .create();
+            let _good = server
+                .mock("GET", "/cdn/0.0.0/data/en_US/good.json")
+                .with_status(200)
+                .with_header("Content-Type", "application/json")
+                .with_header("Content-MD5", "ilvfVP2SGN5DbA8E8AyJbw==")
+                .with_body(r#"["value"]"#)
+                .create();
+
+            let bad = client.get_data::<Vec<String>>("bad.json");
+            assert!(matches!(bad, Err(ClientError::ChecksumMismatch { .. })));
+            assert!(!client.is_cached("bad.json"));
+
+            assert_eq!(client.get_data::<Vec<String>>("good.json").unwrap(), ["value"]);
+            assert!(client.is_cached("good.json"));
+        }
+
+        #[test]
+        fn is_cached_checks_cache_for_data_file() {
+            let (_server, url, client) = create_mock_client();
+            assert!(!client.is_cached("data.json"));
+
+            let dir = std::env::temp_dir().join("ddragon-is-cached-sync");
+            let cache = DiskCache::new(dir.to_str().unwrap());
+            cache.put(&format!("{}/cdn/0.0.0/data/en_US/data.json", url), b"[]");
+            let client = Client { cache: Some(Arc::new(cache)), ..client };
+
+            assert!(client.is_cached("data.json"));
+            assert!(!client.is_cached("other.json"));
+        }
+
+        #[test]
+        fn prewarm_skips_cached_files_and_reports_failures() {
+            let (mut server, url, client) = create_mock_client();
+            let _cached =
+                server.mock("GET", "/cdn/0.0.0/data/en_US/cached.json").expect(0).create();
+            let _fresh = server
+                .mock("GET", "/cdn/0.0.0/data/en_US/fresh.json")
+                .with_status(200)
+                .with_header("Content-Type", "application/json")
+                .with_body("[]")
+                .expect(1)
+                .create();
+            let _missing =
+                server.mock("GET", "/cdn/0.0.0/data/en_US/missing.json").with_status(404).create();
+
+            let dir = std::env::temp_dir().join("ddragon-prewarm-sync");
+            let _ = std::fs::remove_dir_all(&dir);
+            let cache: Arc<dyn Cache> = Arc::new(DiskCache::new(dir.to_str().unwrap()));
+            cache.put(&format!("{}/cdn/0.0.0/data/en_US/cached.json", url), b"[]");
+            let agent = Agent::config_builder()
+                .middleware(CacheMiddleware::from_shared(cache.clone()))
+                .build()
+                .into();
+            let client = Client { agent, cache: Some(cache), ..client };
+
+            let result = client.prewarm(&["cached.json", "fresh.json", "missing.json"]);
+            let Err(ClientError::Prewarm { failures }) = result else {
+                panic!("expected a prewarm error, got {:?}", result);
+            };
+            assert_eq!(failures.len(), 1);
+            assert_eq!(failures[0].0, "missing.json");
+            assert!(client.is_cached("fresh.json"));
+            _cached.assert();
+            _fresh.assert();
+        }
+
+        #[test]
+        fn cache_age_reads_cache_timestamps() {
+            let (_server, url, client) = create_mock_client();
+            assert_eq!(client.cache_age("data.json"), None);
+
+            let dir = std::env::temp_dir().join("ddragon-cache-age-sync");
+            let cache = DiskCache::new(dir.to_str().unwrap());
+            cache.put(&format!("{}/cdn/0.0.0/data/en_US/data.json", url), b"[]");
+            let client = Client { cache: Some(Arc::new(cache)), ..client };
+
+            assert!(client.cache_age("data.json").unwrap() < Duration::from_secs(60));
+            assert_eq!(client.cache_age("other.json"), None);
+        }
+
+        #[test]
+        fn fetch_with_stats_reports_size_and_cache_hits() {
+            let (mut server, _url, client) = create_mock_client();
+            let _mock = server
+                .mock("GET", "/cdn/0.0.0/data/en_US/data.json")
+                .with_status(200)
+                .with_header("Content-Type", "application/json")
+                .with_body(r#"["a","b"]"#)
+                .expect(1)
+                .create();
+
+            let dir = std::env::temp_dir().join("ddragon-fetch-stats-sync");
+            let _ = std::fs::remove_dir_all(&dir);
+            let agent = Agent::config_builder()
+                .middleware(CacheMiddleware::new(dir.to_str().unwrap()))
+                .build()
+                .into();
+            let client = Client { agent, ..client };
+
+            let (data, stats) = client.fetch_with_stats::<Vec<String>>("data.json").unwrap();
+            assert_eq!(data, vec!["a", "b"]);
+            assert_eq!(stats.bytes, 9);
+            assert!(!stats.from_cache);
+
+            let (_, stats) = client.fetch_with_stats::<Vec<String>>("data.json").unwrap();
+            assert_eq!(stats.bytes, 9);
+            assert!(stats.from_cache);
+        }
+
+        #[test]
+        fn get_cdn_joins_onto_base_url() {
+            let (mut server, _url, client) = create_mock_client();
+            let _mock = server
+                .mock("GET", "/cdn/languages.json")
+                .with_status(200)
+                .with_header("Content-Type", "application/json")
+                .with_body(r#"["en_US","ko_KR"]"#)
+                .create();
+
+            assert_eq!(
+                client.get_cdn("cdn/languages.json").unwrap(),
+                serde_json::json!(["en_US", "ko_KR"])
+            );
+        }
+
+        #[test]
+        fn estimate_patch_size_sums_content_lengths() {
+            let (mut server, _url, client) = create_mock_client();
+            let _mock = server
+                .mock("HEAD", mockito::Matcher::Regex(r"^/cdn/0\.0\.0/data/en_US/.+\.json$".into()))
+                .match_header("Accept-Language", "en-US")
+                .with_status(200)
+                .with_header("Content-Length", "100")
+                .expect(crate::fetch_options::DATA_FILES.len())
+                .create();
+
+            assert_eq!(
+                client.estimate_patch_size().unwrap(),
+                100 * crate::fetch_options::DATA_FILES.len() as u64
+            );
+            _mock.assert();
+        }
+
+        #[test]
+        fn get_data_with_overrides_locale_and_version() {
+            let (mut server, _url, client) = create_mock_client();
+            let _mock = server
+                .mock("GET", "/cdn/1.1.1/data/ko_KR/data.json")
+                .match_header("Accept-Language", "ko-KR")
+                .with_status(200)
+                .with_header("Content-Type", "application/json")
+                .with_body(r#"["value"]"#)
+                .create();
+
+            let options = FetchOptions::new().locale("ko_KR").version("1.1.1");
+            assert_eq!(
+                client.get_data_with::<Vec<String>>("data.json", &options).unwrap(),
+                vec!["value".to_owned()]
+            );
+        }
+
+        #[test]
+        fn empty_datasets_are_flagged_when_configured() {
+            let (mut server, _url, client) = create_mock_client();
+            let _mock = server
+                .mock("GET", "/cdn/0.0.0/data/en_US/data.json")
+                .with_status(200)
+                .with_header("Content-Type", "application/json")
+                .with_body(r#"{"type":"champion","data":{}}"#)
+                .create();
+
+            let allowed = client.get_data::<Value>("data.json");
+            assert!(allowed.is_ok());
+
+            let client = Client { empty_datasets: EmptyDatasets::Warn, ..client };
+            assert!(client.get_data::<Value>("data.json").is_ok());
+
+            let client = Client { empty_datasets: EmptyDatasets::Error, ..client };
+            let rejected = client.get_data::<Value>("data.json");
+            assert!(
+                matches!(rejected, Err(ClientError::EmptyDataset { endpoint }) if endpoint == "data.json")
+            );
+        }
+
+        #[test]
+        fn checked_data_reports_where_parsing_failed() {
+            let (mut server, _url, client) = create_mock_client();
+            let client = Client { empty_datasets: EmptyDatasets::Warn, ..client };
+            let _mock = server
+                .mock("GET", "/cdn/0.0.0/data/en_US/data.json")
+                .with_status(200)
+                .with_header("Content-Type", "application/json")
+                .with_body("[\n\"a\",\n1\n]")
+                .create();
+
+            let failed = client.get_data::<Vec<String>>("data.json");
+            assert!(
+                matches!(failed, Err(ClientError::Json { line: 3, column: 1, snippet, .. }) if snippet.contains("1"))
+            );
+        }
+
+        #[test]
+        fn fetch_with_stats_checks_empty_datasets() {
+            let (mut server, _url, client) = create_mock_client();
+            let client = Client { empty_datasets: EmptyDatasets::Error, ..client };
+            let _mock = server
+                .mock("GET", "/cdn/0.0.0/data/en_US/data.json")
+                .with_status(200)
+                .with_header("Content-Type", "application/json")
+                .with_body(r#"{"type":"champion","data":{}}"#)
+                .create();
+
+            let rejected = client.fetch_with_stats::<Value>("data.json");
+            assert!(
+                matches!(rejected, Err(ClientError::EmptyDataset { endpoint }) if endpoint == "data.json")
+            );
+        }
+
+        #[test]
+        fn get_data_fills_missing_names_from_fallback_locale() {
+            let (mut server, _url, client) = create_mock_client();
+            let client = Client { locale_fallback: Some("en_US".to_owned()), ..client };
+            let _primary = server
+                .mock("GET", "/cdn/0.0.0/data/ko_KR/data.json")
+                .with_status(200)
+                .with_header("Content-Type", "application/json")
+                .with_body(r#"[{"name": "부츠"}, {"name": ""}]"#)
+                .create();
+            let fallback = server
+                .mock("GET", "/cdn/0.0.0/data/en_US/data.json")
+                .with_status(200)
+                .with_header("Content-Type", "application/json")
+                .with_body(r#"[{"name": "Boots"}, {"name": "Faerie Charm"}]"#)
+                .create();
+
+            let options = FetchOptions::new().locale("ko_KR");
+            let data: Vec<std::collections::HashMap<String, String>> =
+                client.get_data_with("data.json", &options).unwrap();
+
+            assert_eq!(data[0]["name"], "부츠");
+            assert_eq!(data[1]["name"], "Faerie Charm");
+            fallback.assert();
+        }
+
+        #[cfg(feature = "image")]
+        #[test]
+        fn crop_icon_crops_and_reuses_sprite() {
+            let (mut server, _url, client) = create_mock_client();
+            let mut sprite_bytes = Vec::new();
+            DynamicImage::new_rgba8(4, 2)
+                .write_to(&mut Cursor::new(&mut sprite_bytes), ImageOutputFormat::Png)
+                .unwrap();
+            let mock = server
+                .mock("GET", "/cdn/0.0.0/img/sprite/champion0.png")
+                .with_status(200)
+                .with_header("Content-Type", "image/png")
+                .with_body(sprite_bytes)
+                .expect(1)
+                .create();
+
+            let image = Image {
+                full: "Aatrox.png".to_owned(),
+                sprite: "champion0.png".to_owned(),
+                group: "champion".to_owned(),
+                x: 2,
+                y: 0,
+                w: 2,
+                h: 2,
+            };
+            let icon = load_from_memory(&client.crop_icon(&image).unwrap()).unwrap();
+            assert_eq!((icon.width(), icon.height()), (2, 2));
+
+            assert!(client.crop_icon(&Image { x: 0, ..image }).is_ok());
+            mock.assert();
+        }
     }
 }
@@ -2,6 +2,8 @@
 
 use thiserror::Error;
 
+use crate::integrity::ChecksumMismatch;
+
 #[derive(Error, Debug)]
 /// Any potential error the client may run into during operation.
 pub enum ClientError {
@@ -13,25 +15,58 @@ pub enum ClientError {
     #[error("Could not complete request.")]
     /// Indicates a request failed, for the same reasons any `ureq` request may
     /// fail.
-    Request(#[from] Box<ureq::Error>),
+    Request(Box<ureq::Error>),
     #[cfg(feature = "async-base")]
     #[error("Could not complete request.")]
     /// Indicates a request failed, for the same reasons any `reqwest` request
     /// may fail.
-    AsyncRequest(#[from] reqwest::Error),
+    AsyncRequest(reqwest::Error),
     #[cfg(feature = "async-base")]
     #[error("Could not complete request.")]
     /// Indicates a request failed, for the same reasons any `reqwest-middlware`
     /// request may fail.
-    AsyncMiddlewareRequest(#[from] reqwest_middleware::Error),
+    AsyncMiddlewareRequest(reqwest_middleware::Error),
+    #[error("The request timed out.")]
+    /// Indicates a request took longer than the configured timeout, e.g.
+    /// because the CDN is slow to respond, as opposed to being unreachable.
+    Timeout,
     #[error("Could not parse JSON data.")]
     /// Indicates a failed attempt at parsing JSON data.
     Parse(#[from] std::io::Error),
+    #[error("Could not parse JSON data at line {line}, column {column}.")]
+    /// Indicates a response could not be parsed into the expected model, for
+    /// example because a new patch changed the shape of a dataset. `snippet`
+    /// holds the part of the response around where parsing failed.
+    Json {
+        #[source]
+        source: serde_json::Error,
+        line: usize,
+        column: usize,
+        snippet: String,
+    },
+    #[error("Could not read data from the local mirror.")]
+    /// Indicates a file in a local ddragon mirror could not be read.
+    LocalFile(#[source] std::io::Error),
     #[cfg(feature = "image")]
     #[error("Could not parse image data.")]
     /// Indicates that attempting to convert bytes to a [image::DynamicImage]
     /// failed.
     Image(#[from] image::ImageError),
+    #[error(
+        "The version list returned unexpected content (status {}).",
+        .status.map_or_else(|| "n/a".to_owned(), |status| status.to_string())
+    )]
+    /// Indicates `versions.json` returned something other than a list of
+    /// versions, such as an error page during an outage. `status` is the HTTP
+    /// status code (absent for a local mirror) and `snippet` holds the start of
+    /// the response.
+    UnexpectedVersions { status: Option<u16>, snippet: String },
+    #[error("Downloaded data did not match its checksum (expected {expected}, got {actual}).")]
+    /// Indicates a downloaded body didn't match the `Content-MD5` header sent
+    /// with it, e.g. because it was truncated. Only returned when checksum
+    /// verification is enabled, and the body is not cached. Both checksums are
+    /// base64-encoded MD5 digests.
+    ChecksumMismatch { expected: String, actual: String },
     #[error("Could not find the latest API version.")]
     /// Indicates during instantiation that the version lists provided by the
     /// ddragon API was empty.
@@ -40,4 +75,108 @@ pub enum ClientError {
     /// Indicates data for the requested champion couldn't be found in the
     /// parsed document.
     NoChampionData,
+    #[error("The dataset {endpoint} was unexpectedly empty.")]
+    /// Indicates a dataset came back with no entries, e.g. while the CDN is
+    /// partway through deploying a patch. Only returned when the client is
+    /// configured to reject empty datasets with
+    /// [EmptyDatasets::Error](crate::EmptyDatasets::Error).
+    EmptyDataset { endpoint: String },
+    #[error(
+        "Could not prewarm {}.",
+        .failures.iter().map(|(endpoint, _)| endpoint.as_str()).collect::<Vec<_>>().join(", ")
+    )]
+    /// Indicates some datasets could not be fetched while warming the cache.
+    /// `failures` pairs each endpoint that failed with the error it ran into;
+    /// every other endpoint was cached successfully.
+    Prewarm { failures: Vec<(String, ClientError)> },
+}
+
+impl From<ChecksumMismatch> for ClientError {
+    fn from(mismatch: ChecksumMismatch) -> Self {
+        Self::ChecksumMismatch { expected: mismatch.expected, actual: mismatch.actual }
+    }
+}
+
+#[cfg(feature = "sync")]
+impl From<Box<ureq::Error>> for ClientError {
+    fn from(error: Box<ureq::Error>) -> Self {
+        match error.as_ref() {
+            ureq::Error::Timeout(_) => return Self::Timeout,
+            ureq::Error::Io(io) if io.kind() == std::io::ErrorKind::TimedOut => {
+                return Self::Timeout
+            }
+            ureq::Error::Io(io) => {
+                if let Some(mismatch) =
+                    io.get_ref().and_then(|e| e.downcast_ref::<ChecksumMismatch>())
+                {
+                    return mismatch.clone().into();
+                }
+            }
+            _ => {}
+        }
+        Self::Request(error)
+    }
+}
+
+#[cfg(feature = "async-base")]
+impl From<reqwest::Error> for ClientError {
+    fn from(error: reqwest::Error) -> Self {
+        if error.is_timeout() {
+            return Self::Timeout;
+        }
+        Self::AsyncRequest(error)
+    }
+}
+
+#[cfg(feature = "async-base")]
+impl From<reqwest_middleware::Error> for ClientError {
+    fn from(error: reqwest_middleware::Error) -> Self {
+        match &error {
+            reqwest_middleware::Error::Reqwest(inner) if inner.is_timeout() => {
+                return Self::Timeout
+            }
+            // Other middleware, such as the cache, may wrap errors in errors
+            // of their own.
+            reqwest_middleware::Error::Middleware(inner) => {
+                if let Some(mismatch) =
+                    inner.chain().find_map(|e| e.downcast_ref::<ChecksumMismatch>())
+                {
+                    return mismatch.clone().into();
+                }
+                if inner
+                    .chain()
+                    .filter_map(|e| e.downcast_ref::<reqwest::Error>())
+                    .any(|e| e.is_timeout())
+                {
+                    return Self::Timeout;
+                }
+            }
+            _ => {}
+        }
+        Self::AsyncMiddlewareRequest(error)
+    }
+}
+
+/// How many bytes either side of a JSON parse failure are kept in the snippet.
+const SNIPPET_RADIUS: usize = 40;
+
+impl ClientError {
+    pub(crate) fn unexpected_versions(status: Option<u16>, body: &[u8]) -> Self {
+        let snippet = &body[..body.len().min(SNIPPET_RADIUS * 2)];
+        Self::UnexpectedVersions { status, snippet: String::from_utf8_lossy(snippet).into_owned() }
+    }
+
+    pub(crate) fn json(source: serde_json::Error, body: &[u8]) -> Self {
+        let (line, column) = (source.line(), source.column());
+        let line_start: usize = body
+            .split(|&byte| byte == b'\n')
+            .take(line.saturating_sub(1))
+            .map(|line| line.len() + 1)
+            .sum();
+        let offset = (line_start + column.saturating_sub(1)).min(body.len());
+        let snippet =
+            &body[offset.saturating_sub(SNIPPET_RADIUS)..(offset + SNIPPET_RADIUS).min(body.len())];
+
+        Self::Json { source, line, column, snippet: String::from_utf8_lossy(snippet).into_owned() }
+    }
 }
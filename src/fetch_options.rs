@@ -0,0 +1,129 @@
+#![cfg_attr(docsrs, doc(cfg(any(feature = "sync", feature = "async"))))]
+
+use serde_json::Value;
+use std::time::Duration;
+
+use crate::ClientError;
+
+/// Per-call overrides for the locale and version used when fetching data.
+/// Anything left unset falls back to the client's own settings.
+///
+/// ```
+/// use ddragon::FetchOptions;
+///
+/// let options = FetchOptions::new().locale("ko_KR").version("13.1.1");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FetchOptions {
+    /// The locale to fetch data in, e.x. `ko_KR`.
+    pub locale: Option<String>,
+    /// The ddragon version to fetch data from, e.x. `13.1.1`.
+    pub version: Option<String>,
+}
+
+impl FetchOptions {
+    /// Creates a [FetchOptions] with no overrides set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Overrides the locale for this call.
+    pub fn locale(mut self, locale: &str) -> Self {
+        self.locale = Some(locale.to_owned());
+        self
+    }
+
+    /// Overrides the version for this call.
+    pub fn version(mut self, version: &str) -> Self {
+        self.version = Some(version.to_owned());
+        self
+    }
+}
+
+/// How a single fetch made with `fetch_with_stats` went.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FetchStats {
+    /// The size of the response body, after any decompression.
+    pub bytes: u64,
+    /// Whether the body was served from the client's cache rather than the
+    /// network. Always `false` for local mirrors and uncached clients.
+    pub from_cache: bool,
+    /// How long the fetch took, not counting deserialization.
+    pub duration: Duration,
+}
+
+/// What a client does when a dataset that normally has entries, e.x.
+/// `champion.json`, comes back valid but empty, as can happen while the CDN
+/// is partway through deploying a patch.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum EmptyDatasets {
+    /// Return the empty dataset as-is.
+    #[default]
+    Allow,
+    /// Return the empty dataset, but log a warning when the `logging` feature
+    /// is enabled.
+    Warn,
+    /// Fail with [ClientError::EmptyDataset].
+    Error,
+}
+
+/// Applies `policy` to a fetched dataset, which counts as empty when its
+/// `data` map (or the document itself, for list datasets) has no entries.
+pub(crate) fn check_empty_dataset(
+    data: &Value,
+    endpoint: &str,
+    policy: EmptyDatasets,
+) -> Result<(), ClientError> {
+    let entries = data.get("data").unwrap_or(data);
+    let is_empty = match entries {
+        Value::Object(object) => object.is_empty(),
+        Value::Array(array) => array.is_empty(),
+        _ => false,
+    };
+    match policy {
+        EmptyDatasets::Warn if is_empty => {
+            warn!("dataset {} came back empty", endpoint);
+            Ok(())
+        }
+        EmptyDatasets::Error if is_empty => {
+            Err(ClientError::EmptyDataset { endpoint: endpoint.to_owned() })
+        }
+        _ => Ok(()),
+    }
+}
+
+/// The header set to `HIT` on responses served from a cache, both by
+/// `http-cache` and by our own `CacheMiddleware`.
+pub(crate) const CACHE_STATUS_HEADER: &str = "x-cache";
+
+/// The dataset files fetched by the client's endpoint methods, relative to
+/// the data URL of a version and locale.
+pub(crate) const DATA_FILES: &[&str] = &[
+    "challenges.json",
+    "champion.json",
+    "championFull.json",
+    "item.json",
+    "map.json",
+    "mission-assets.json",
+    "profileicon.json",
+    "runesReforged.json",
+    "spellbuffs.json",
+    "sticker.json",
+    "summoner.json",
+    "language.json",
+    "tft-arena.json",
+    "tft-augments.json",
+    "tft-champion.json",
+    "tft-hero-augments.json",
+    "tft-item.json",
+    "tft-queues.json",
+    "tft-regalia.json",
+    "tft-tactician.json",
+    "tft-trait.json",
+];
+
+/// Converts a ddragon locale into an `Accept-Language` value, e.x. `en_US`
+/// into `en-US`.
+pub(crate) fn accept_language(locale: &str) -> String {
+    locale.replace('_', "-")
+}
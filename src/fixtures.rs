@@ -0,0 +1,253 @@
+#![cfg_attr(docsrs, doc(cfg(feature = "test-fixtures")))]
+//! Each constant is a trimmed-down but valid copy of a ddragon file, so
+//! downstream tests can exercise the models without fetching live data.
+//!
+//! ```
+//! use ddragon::{fixtures, models::Champions};
+//!
+//! let champions = Champions::from_json(fixtures::CHAMPIONS.as_bytes()).unwrap();
+//! assert!(champions.data.contains_key("Ahri"));
+//! ```
+
+/// A `champion.json` listing only Ahri.
+pub const CHAMPIONS: &str = r#"{
+    "type": "champion",
+    "format": "standAloneComplex",
+    "version": "14.1.1",
+    "data": {
+        "Ahri": {
+            "version": "14.1.1",
+            "id": "Ahri",
+            "key": "103",
+            "name": "Ahri",
+            "title": "the Nine-Tailed Fox",
+            "blurb": "Innately connected to the magic of the spirit realm, Ahri is a fox-like vastaya who can manipulate her prey's emotions and consume their essence.",
+            "info": { "attack": 3, "defense": 4, "magic": 8, "difficulty": 5 },
+            "image": {
+                "full": "Ahri.png",
+                "sprite": "champion0.png",
+                "group": "champion",
+                "x": 48, "y": 0, "w": 48, "h": 48
+            },
+            "tags": ["Mage", "Assassin"],
+            "partype": "Mana",
+            "stats": {
+                "hp": 590, "hpperlevel": 104, "mp": 418, "mpperlevel": 25, "movespeed": 330,
+                "armor": 21, "armorperlevel": 4.2, "spellblock": 30, "spellblockperlevel": 1.3,
+                "attackrange": 550, "hpregen": 2.5, "hpregenperlevel": 0.6, "mpregen": 8,
+                "mpregenperlevel": 0.8, "crit": 0, "critperlevel": 0, "attackdamage": 53,
+                "attackdamageperlevel": 3, "attackspeedperlevel": 2.2, "attackspeed": 0.668
+            }
+        }
+    }
+}"#;
+
+/// A `championFull.json` listing only Ahri, with just her Q ability.
+pub const CHAMPIONS_FULL: &str = r#"{
+    "type": "champion",
+    "format": "full",
+    "version": "14.1.1",
+    "keys": { "103": "Ahri" },
+    "data": {
+        "Ahri": {
+            "id": "Ahri",
+            "key": "103",
+            "name": "Ahri",
+            "title": "the Nine-Tailed Fox",
+            "image": {
+                "full": "Ahri.png",
+                "sprite": "champion0.png",
+                "group": "champion",
+                "x": 48, "y": 0, "w": 48, "h": 48
+            },
+            "skins": [
+                { "id": "103000", "num": 0, "name": "default", "chromas": false },
+                { "id": "103001", "num": 1, "name": "Dynasty Ahri", "chromas": false }
+            ],
+            "lore": "Innately connected to the magic of the spirit realm, Ahri is a fox-like vastaya who can manipulate her prey's emotions and consume their essence.",
+            "blurb": "Innately connected to the magic of the spirit realm, Ahri is a fox-like vastaya who can manipulate her prey's emotions and consume their essence.",
+            "allytips": ["Use Charm to set up combos."],
+            "enemytips": ["Stay behind minions to avoid Charm."],
+            "tags": ["Mage", "Assassin"],
+            "partype": "Mana",
+            "info": { "attack": 3, "defense": 4, "magic": 8, "difficulty": 5 },
+            "stats": {
+                "hp": 590, "hpperlevel": 104, "mp": 418, "mpperlevel": 25, "movespeed": 330,
+                "armor": 21, "armorperlevel": 4.2, "spellblock": 30, "spellblockperlevel": 1.3,
+                "attackrange": 550, "hpregen": 2.5, "hpregenperlevel": 0.6, "mpregen": 8,
+                "mpregenperlevel": 0.8, "crit": 0, "critperlevel": 0, "attackdamage": 53,
+                "attackdamageperlevel": 3, "attackspeedperlevel": 2.2, "attackspeed": 0.668
+            },
+            "spells": [
+                {
+                    "id": "AhriQ",
+                    "name": "Orb of Deception",
+                    "description": "Ahri sends out and pulls back her orb, dealing magic damage on the way out and true damage on the way back.",
+                    "tooltip": "Ahri throws her orb, dealing <magicDamage>{{ totaldamage }} magic damage</magicDamage>.",
+                    "leveltip": {
+                        "label": ["Damage", "@AbilityResourceName@ Cost"],
+                        "effect": ["{{ basedamage }} -> {{ basedamageNL }}", "{{ cost }} -> {{ costNL }}"]
+                    },
+                    "maxrank": 5,
+                    "cooldown": [7, 7, 7, 7, 7],
+                    "cooldownBurn": "7",
+                    "cost": [55, 65, 75, 85, 95],
+                    "costBurn": "55/65/75/85/95",
+                    "effect": [null, [40, 65, 90, 115, 140], [0, 0, 0, 0, 0]],
+                    "effectBurn": [null, "40/65/90/115/140", "0"],
+                    "vars": [],
+                    "costType": " {{ abilityresourcename }}",
+                    "maxammo": "-1",
+                    "range": [970, 970, 970, 970, 970],
+                    "rangeBurn": "970",
+                    "image": {
+                        "full": "AhriQ.png",
+                        "sprite": "spell0.png",
+                        "group": "spell",
+                        "x": 384, "y": 0, "w": 48, "h": 48
+                    },
+                    "resource": "{{ cost }} {{ abilityresourcename }}"
+                }
+            ],
+            "passive": {
+                "name": "Essence Theft",
+                "description": "After killing 9 minions or monsters, Ahri heals.",
+                "image": {
+                    "full": "Ahri_SoulEater2.png",
+                    "sprite": "passive0.png",
+                    "group": "passive",
+                    "x": 48, "y": 0, "w": 48, "h": 48
+                }
+            },
+            "recommended": []
+        }
+    }
+}"#;
+
+/// An `item.json` with Boots and the Berserker's Greaves built from them.
+pub const ITEMS: &str = r#"{
+    "type": "item",
+    "version": "14.1.1",
+    "data": {
+        "1001": {
+            "name": "Boots",
+            "description": "<mainText><stats><attention>25</attention> Move Speed</stats></mainText>",
+            "colloq": ";",
+            "plaintext": "Slightly increases Move Speed",
+            "into": ["3006"],
+            "image": {
+                "full": "1001.png",
+                "sprite": "item0.png",
+                "group": "item",
+                "x": 0, "y": 0, "w": 48, "h": 48
+            },
+            "gold": { "base": 300, "purchasable": true, "total": 300, "sell": 210 },
+            "tags": ["Boots"],
+            "maps": { "11": true, "12": true },
+            "stats": { "FlatMovementSpeedMod": 25 }
+        },
+        "3006": {
+            "name": "Berserker's Greaves",
+            "description": "<mainText><stats><attention>35%</attention> Attack Speed<br><attention>45</attention> Move Speed</stats></mainText>",
+            "colloq": ";",
+            "plaintext": "Enhances Move Speed and Attack Speed",
+            "from": ["1001"],
+            "image": {
+                "full": "3006.png",
+                "sprite": "item0.png",
+                "group": "item",
+                "x": 48, "y": 0, "w": 48, "h": 48
+            },
+            "gold": { "base": 800, "purchasable": true, "total": 1100, "sell": 770 },
+            "tags": ["AttackSpeed", "Boots"],
+            "maps": { "11": true, "12": true },
+            "stats": { "FlatMovementSpeedMod": 45, "PercentAttackSpeedMod": 0.35 },
+            "depth": 2
+        }
+    },
+    "groups": [],
+    "tree": []
+}"#;
+
+/// A `summoner.json` with Flash and Ignite.
+pub const SUMMONER_SPELLS: &str = r#"{
+    "type": "summoner",
+    "version": "14.1.1",
+    "data": {
+        "SummonerFlash": {
+            "id": "SummonerFlash",
+            "name": "Flash",
+            "description": "Teleports your champion a short distance toward your cursor's location.",
+            "tooltip": "Teleports your champion a short distance toward your cursor's location.",
+            "maxrank": 1,
+            "cooldown": [300],
+            "cooldownBurn": "300",
+            "cost": [0],
+            "costBurn": "0",
+            "effect": [null, [400], [0], [0]],
+            "effectBurn": [null, "400", "0", "0"],
+            "key": "4",
+            "summonerLevel": 7,
+            "modes": ["CLASSIC", "ARAM", "URF"],
+            "costType": "No Cost",
+            "maxammo": "-1",
+            "range": [425],
+            "rangeBurn": "425",
+            "image": {
+                "full": "SummonerFlash.png",
+                "sprite": "spell0.png",
+                "group": "spell",
+                "x": 288, "y": 0, "w": 48, "h": 48
+            },
+            "resource": "No Cost"
+        },
+        "SummonerDot": {
+            "id": "SummonerDot",
+            "name": "Ignite",
+            "description": "Ignites target enemy champion, dealing true damage over 5 seconds.",
+            "tooltip": "Deals <trueDamage>{{ tooltiptruedamagecalculation }} true damage</trueDamage> over 5 seconds.",
+            "maxrank": 1,
+            "cooldown": [180],
+            "cooldownBurn": "180",
+            "cost": [0],
+            "costBurn": "0",
+            "effect": [null, [10]],
+            "effectBurn": [null, "10"],
+            "key": "14",
+            "summonerLevel": 9,
+            "modes": ["CLASSIC", "ARAM", "URF"],
+            "costType": "No Cost",
+            "maxammo": "-1",
+            "range": [600],
+            "rangeBurn": "600",
+            "image": {
+                "full": "SummonerDot.png",
+                "sprite": "spell0.png",
+                "group": "spell",
+                "x": 192, "y": 0, "w": 48, "h": 48
+            },
+            "resource": "No Cost"
+        }
+    }
+}"#;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::models::{Champions, ChampionsFull, Items, SummonerSpells};
+
+    #[test]
+    fn fixtures_deserialize() {
+        let champions = Champions::from_json(CHAMPIONS.as_bytes()).unwrap();
+        assert_eq!(champions.data["Ahri"].key, "103");
+
+        let champions_full = ChampionsFull::from_json(CHAMPIONS_FULL.as_bytes()).unwrap();
+        assert_eq!(champions_full.data["Ahri"].spells[0].id, "AhriQ");
+
+        let items = Items::from_json(ITEMS.as_bytes()).unwrap();
+        assert!(items.validate_references().is_empty());
+
+        let spells = SummonerSpells::from_json(SUMMONER_SPELLS.as_bytes()).unwrap();
+        assert_eq!(spells.summoners_rift_only().len(), 2);
+    }
+}
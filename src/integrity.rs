@@ -0,0 +1,140 @@
+//! Verifies downloaded bodies against the `Content-MD5` checksum sent with
+//! them, so truncated or corrupted responses fail instead of being cached.
+
+use std::fmt;
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use md5::{Digest, Md5};
+
+/// The header servers use to send the base64-encoded MD5 digest of a body.
+const CONTENT_MD5: &str = "content-md5";
+
+/// A body whose digest didn't match the `Content-MD5` header sent with it.
+#[derive(Debug, Clone)]
+pub(crate) struct ChecksumMismatch {
+    pub(crate) expected: String,
+    pub(crate) actual: String,
+}
+
+impl fmt::Display for ChecksumMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Content-MD5 mismatch (expected {}, got {})", self.expected, self.actual)
+    }
+}
+
+impl std::error::Error for ChecksumMismatch {}
+
+/// Checks `body` against a `Content-MD5` header value.
+pub(crate) fn verify_content_md5(expected: &str, body: &[u8]) -> Result<(), ChecksumMismatch> {
+    let expected = expected.trim();
+    let actual = STANDARD.encode(Md5::digest(body));
+    if expected == actual {
+        Ok(())
+    } else {
+        Err(ChecksumMismatch { expected: expected.to_owned(), actual })
+    }
+}
+
+#[cfg(feature = "sync")]
+mod sync {
+    use ureq::http::{header::CONTENT_LENGTH, Method, Request, Response};
+    use ureq::middleware::{Middleware, MiddlewareNext};
+    use ureq::{Body, Error, SendBody};
+
+    use super::{verify_content_md5, CONTENT_MD5};
+
+    /// Fails responses whose body doesn't match their `Content-MD5` header.
+    /// Responses without the header, and responses to anything other than a
+    /// `GET`, are passed through untouched.
+    pub(crate) struct ChecksumMiddleware;
+
+    impl Middleware for ChecksumMiddleware {
+        fn handle(
+            &self,
+            request: Request<SendBody>,
+            next: MiddlewareNext,
+        ) -> Result<Response<Body>, Error> {
+            let is_get = request.method() == Method::GET;
+            let mut response = next.handle(request)?;
+            let expected = match response.headers().get(CONTENT_MD5).map(|h| h.to_str()) {
+                Some(Ok(expected)) if is_get && response.status() == 200 => expected.to_owned(),
+                _ => return Ok(response),
+            };
+
+            let body_mut = response.body_mut();
+            let body = body_mut.read_to_vec()?;
+            verify_content_md5(&expected, &body)
+                .map_err(|e| Error::Io(std::io::Error::new(std::io::ErrorKind::InvalidData, e)))?;
+
+            let mut body_builder = Body::builder();
+            if let Some(mime_type) = body_mut.mime_type() {
+                body_builder = body_builder.mime_type(mime_type);
+            }
+            // Keep every original header, e.g. `Last-Modified` for revalidation.
+            let (mut parts, _) = response.into_parts();
+            parts.headers.insert(CONTENT_LENGTH, body.len().into());
+            Ok(Response::from_parts(parts, body_builder.data(body)))
+        }
+    }
+}
+
+#[cfg(feature = "sync")]
+pub(crate) use sync::ChecksumMiddleware;
+
+#[cfg(feature = "async-base")]
+mod async_base {
+    use reqwest::{Method, Request, Response};
+    use reqwest_middleware::{Error, Middleware, Next, Result};
+    use task_local_extensions::Extensions;
+
+    use super::{verify_content_md5, CONTENT_MD5};
+
+    /// Fails responses whose body doesn't match their `Content-MD5` header.
+    /// Responses without the header, and responses to anything other than a
+    /// `GET`, are passed through untouched.
+    pub(crate) struct AsyncChecksumMiddleware;
+
+    #[async_trait::async_trait]
+    impl Middleware for AsyncChecksumMiddleware {
+        async fn handle(
+            &self,
+            request: Request,
+            extensions: &mut Extensions,
+            next: Next<'_>,
+        ) -> Result<Response> {
+            let is_get = request.method() == Method::GET;
+            let response = next.run(request, extensions).await?;
+            let expected = match response.headers().get(CONTENT_MD5).map(|h| h.to_str()) {
+                Some(Ok(expected)) if is_get && response.status() == 200 => expected.to_owned(),
+                _ => return Ok(response),
+            };
+
+            let mut rebuilt = http::Response::builder().status(response.status());
+            for (name, value) in response.headers() {
+                rebuilt = rebuilt.header(name, value);
+            }
+            let body = response.bytes().await?;
+            verify_content_md5(&expected, &body).map_err(Error::middleware)?;
+
+            Ok(rebuilt.body(body).map_err(Error::middleware)?.into())
+        }
+    }
+}
+
+#[cfg(feature = "async-base")]
+pub(crate) use async_base::AsyncChecksumMiddleware;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn verify_content_md5_compares_base64_digests() {
+        assert!(verify_content_md5("kAFQmDzST7DWlj99KOF/cg==", b"abc").is_ok());
+        assert!(verify_content_md5(" kAFQmDzST7DWlj99KOF/cg== ", b"abc").is_ok());
+
+        let mismatch = verify_content_md5("kAFQmDzST7DWlj99KOF/cg==", b"ab").unwrap_err();
+        assert_eq!(mismatch.expected, "kAFQmDzST7DWlj99KOF/cg==");
+        assert_ne!(mismatch.actual, mismatch.expected);
+    }
+}
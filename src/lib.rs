@@ -1,15 +1,38 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 #![doc = include_str!("../README.md")]
 
+#[cfg(any(feature = "sync", feature = "async-base"))]
+#[macro_use]
+mod logging;
+
 /// Contains the structs that can be returned by the client API.
 pub mod models;
 
+/// Re-exports the most commonly used types for a single glob import.
+pub mod prelude;
+
+#[cfg(feature = "test-fixtures")]
+/// Contains small sample ddragon payloads for testing against the models.
+pub mod fixtures;
+
 #[cfg(any(feature = "sync", feature = "async-base"))]
 mod error;
 
 #[cfg(any(feature = "sync", feature = "async-base"))]
 pub use error::ClientError;
 
+#[cfg(any(feature = "sync", feature = "async-base"))]
+mod fetch_options;
+
+#[cfg(any(feature = "sync", feature = "async-base"))]
+pub use fetch_options::{EmptyDatasets, FetchOptions, FetchStats};
+
+#[cfg(any(feature = "sync", feature = "async-base"))]
+mod locale_fallback;
+
+#[cfg(any(feature = "sync", feature = "async-base"))]
+mod integrity;
+
 #[cfg(feature = "sync")]
 /// Contains the local file caching middleware used for `ureq`.
 pub mod cache_middleware;
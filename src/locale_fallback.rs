@@ -0,0 +1,80 @@
+//! Fills gaps in data fetched for one locale using data from another.
+
+use serde_json::Value;
+
+/// Whether any object in `data` has an empty or null `name`.
+pub(crate) fn has_missing_names(data: &Value) -> bool {
+    match data {
+        Value::Object(object) => {
+            is_missing(object.get("name")) || object.values().any(has_missing_names)
+        }
+        Value::Array(array) => array.iter().any(has_missing_names),
+        _ => false,
+    }
+}
+
+/// Replaces empty or null `name`s in `data` with the `name` at the same
+/// position in `fallback`, where that one isn't missing too.
+pub(crate) fn fill_missing_names(data: &mut Value, fallback: &Value) {
+    match (data, fallback) {
+        (Value::Object(object), Value::Object(fallback)) => {
+            if is_missing(object.get("name")) && !is_missing(fallback.get("name")) {
+                object.insert("name".to_owned(), fallback["name"].clone());
+            }
+            for (key, value) in object.iter_mut() {
+                if let Some(fallback) = fallback.get(key) {
+                    fill_missing_names(value, fallback);
+                }
+            }
+        }
+        (Value::Array(array), Value::Array(fallback)) => {
+            for (value, fallback) in array.iter_mut().zip(fallback) {
+                fill_missing_names(value, fallback);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn is_missing(name: Option<&Value>) -> bool {
+    match name {
+        Some(Value::Null) => true,
+        Some(Value::String(name)) => name.is_empty(),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn detects_empty_and_null_names() {
+        assert!(has_missing_names(&json!({ "data": { "1001": { "name": "" } } })));
+        assert!(has_missing_names(&json!([{ "name": null }])));
+        assert!(!has_missing_names(&json!({ "data": { "1001": { "name": "Boots" } } })));
+    }
+
+    #[test]
+    fn fills_only_missing_names() {
+        let mut data = json!({
+            "data": {
+                "1001": { "name": "", "plaintext": "" },
+                "1004": { "name": "Faerie Charm" }
+            }
+        });
+        let fallback = json!({
+            "data": {
+                "1001": { "name": "Boots", "plaintext": "Slightly increases Move Speed" },
+                "1004": { "name": "Fairy Charm" }
+            }
+        });
+
+        fill_missing_names(&mut data, &fallback);
+
+        assert_eq!(data["data"]["1001"]["name"], "Boots");
+        assert_eq!(data["data"]["1001"]["plaintext"], "");
+        assert_eq!(data["data"]["1004"]["name"], "Faerie Charm");
+    }
+}
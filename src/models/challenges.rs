@@ -1,9 +1,12 @@
 use serde::{Deserialize, Serialize};
 
+use super::shared::number_from_string;
+
 pub type Challenges = Vec<Challenge>;
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct Challenge {
+    #[serde(deserialize_with = "number_from_string")]
     pub id: i64,
     pub name: String,
     pub description: String,
@@ -19,56 +22,154 @@ pub struct Challenge {
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct LevelToIconPath {
     #[serde(rename = "IRON")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub iron: Option<String>,
     #[serde(rename = "BRONZE")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub bronze: Option<String>,
     #[serde(rename = "SILVER")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub silver: Option<String>,
     #[serde(rename = "GOLD")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub gold: Option<String>,
     #[serde(rename = "PLATINUM")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub platinum: Option<String>,
     #[serde(rename = "DIAMOND")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub diamond: Option<String>,
     #[serde(rename = "MASTER")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub master: Option<String>,
     #[serde(rename = "GRANDMASTER")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub grandmaster: Option<String>,
     #[serde(rename = "CHALLENGER")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub challenger: Option<String>,
 }
 
+impl LevelToIconPath {
+    /// The icon path for a tier, if the challenge has one for it.
+    pub fn get(&self, tier: ChallengeTier) -> Option<&str> {
+        let path = match tier {
+            ChallengeTier::Iron => &self.iron,
+            ChallengeTier::Bronze => &self.bronze,
+            ChallengeTier::Silver => &self.silver,
+            ChallengeTier::Gold => &self.gold,
+            ChallengeTier::Platinum => &self.platinum,
+            ChallengeTier::Diamond => &self.diamond,
+            ChallengeTier::Master => &self.master,
+            ChallengeTier::Grandmaster => &self.grandmaster,
+            ChallengeTier::Challenger => &self.challenger,
+        };
+        path.as_deref()
+    }
+
+    /// Each tier's icon path, lowest tier first, skipping tiers without one.
+    pub fn iter(&self) -> impl Iterator<Item = (ChallengeTier, &str)> {
+        ChallengeTier::ALL.into_iter().filter_map(|tier| Some((tier, self.get(tier)?)))
+    }
+}
+
+/// A challenge level, ordered from lowest to highest.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum ChallengeTier {
+    Iron,
+    Bronze,
+    Silver,
+    Gold,
+    Platinum,
+    Diamond,
+    Master,
+    Grandmaster,
+    Challenger,
+}
+
+impl ChallengeTier {
+    /// Every tier, lowest first.
+    pub const ALL: [ChallengeTier; 9] = [
+        Self::Iron,
+        Self::Bronze,
+        Self::Silver,
+        Self::Gold,
+        Self::Platinum,
+        Self::Diamond,
+        Self::Master,
+        Self::Grandmaster,
+        Self::Challenger,
+    ];
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct Thresholds {
     #[serde(rename = "IRON")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub iron: Option<RankReward>,
     #[serde(rename = "BRONZE")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub bronze: Option<RankReward>,
     #[serde(rename = "SILVER")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub silver: Option<RankReward>,
     #[serde(rename = "GOLD")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub gold: Option<RankReward>,
     #[serde(rename = "PLATINUM")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub platinum: Option<RankReward>,
     #[serde(rename = "DIAMOND")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub diamond: Option<RankReward>,
     #[serde(rename = "MASTER")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub master: Option<RankReward>,
     #[serde(rename = "GRANDMASTER")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub grandmaster: Option<RankReward>,
     #[serde(rename = "CHALLENGER")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub challenger: Option<RankReward>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct RankReward {
+    #[serde(deserialize_with = "number_from_string")]
     pub value: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub rewards: Option<Vec<RewardDetails>>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct RewardDetails {
     pub category: String,
+    #[serde(deserialize_with = "number_from_string")]
     pub quantity: i64,
     pub title: String,
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn icon_paths_are_listed_per_tier() {
+        let icons: LevelToIconPath = serde_json::from_value(serde_json::json!({
+            "IRON": "/challenges-images/1-IRON.png",
+            "GOLD": "/challenges-images/1-GOLD.png"
+        }))
+        .unwrap();
+
+        assert_eq!(icons.get(ChallengeTier::Gold), Some("/challenges-images/1-GOLD.png"));
+        assert_eq!(icons.get(ChallengeTier::Silver), None);
+        assert_eq!(
+            icons.iter().collect::<Vec<_>>(),
+            [
+                (ChallengeTier::Iron, "/challenges-images/1-IRON.png"),
+                (ChallengeTier::Gold, "/challenges-images/1-GOLD.png")
+            ]
+        );
+    }
+}
@@ -1,8 +1,13 @@
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::{collections::HashMap, fmt};
 
-use super::shared::{has_image, Image};
+use super::items::Item;
+use super::shared::{
+    effect_at, has_image, html_to_markdown, number_from_string, one_or_many, parse_burn,
+    reconcile_burn, string_from_any, strip_html, HasImage, Image,
+};
 
+#[cfg(any(feature = "sync", feature = "async-base"))]
 #[derive(Serialize, Deserialize)]
 pub(crate) struct ChampionWrapper {
     pub format: String,
@@ -18,7 +23,9 @@ pub struct Champion {
     pub title: String,
     pub image: Image,
     pub skins: Vec<Skin>,
+    #[serde(default)]
     pub lore: String,
+    #[serde(default)]
     pub blurb: String,
     pub allytips: Vec<String>,
     pub enemytips: Vec<String>,
@@ -28,18 +35,258 @@ pub struct Champion {
     pub stats: HashMap<String, f64>,
     pub spells: Vec<Spell>,
     pub passive: Passive,
+    #[serde(default)]
+    pub recommended: Vec<RecommendedSet>,
 }
 
 has_image!(Champion);
 
+impl Champion {
+    /// The champion's passive ability.
+    pub fn passive(&self) -> &Passive {
+        &self.passive
+    }
+
+    /// The champion's epithet, e.x. "the Darkin Blade".
+    pub fn display_title(&self) -> &str {
+        &self.title
+    }
+
+    /// The champion's name and title together, e.x. "Aatrox, the Darkin
+    /// Blade", as shown on splash overlays and champion cards.
+    pub fn full_display(&self) -> String {
+        format!("{}, {}", self.name, self.title)
+    }
+
+    /// The full lore text, suited to wiki-style pages.
+    pub fn lore(&self) -> &str {
+        &self.lore
+    }
+
+    /// The full lore text with any markup removed.
+    pub fn lore_plain(&self) -> String {
+        strip_html(&self.lore)
+    }
+
+    /// The ability bound to the given key, if the champion has one there.
+    pub fn spell(&self, slot: SpellSlot) -> Option<&Spell> {
+        self.spells.get(slot.index())
+    }
+
+    /// The passive followed by each ability in Q/W/E/R order, as shown on an
+    /// ability bar.
+    pub fn abilities(&self) -> Vec<Ability<'_>> {
+        std::iter::once(Ability::Passive(&self.passive))
+            .chain(self.spells.iter().map(Ability::Active))
+            .collect()
+    }
+
+    /// The name of the ability bound to the given key, e.x. "Orb of
+    /// Deception", in the locale the champion data was fetched in.
+    pub fn ability_name(&self, slot: SpellSlot) -> Option<&str> {
+        self.spell(slot).map(|spell| spell.name.as_str())
+    }
+
+    /// The per-rank cost of the ability bound to the given key, in this
+    /// champion's resource where the spell's `costType` refers to it.
+    pub fn spell_cost(&self, slot: SpellSlot) -> Option<SpellCost> {
+        Some(self.spell(slot)?.cost_in(&self.resource_type()))
+    }
+
+    /// Collects the fields usually shown on a champion card into a single
+    /// [ChampionSummary].
+    pub fn summary(&self) -> ChampionSummary {
+        ChampionSummary {
+            id: self.id.clone(),
+            name: self.name.clone(),
+            title: self.title.clone(),
+            tags: self.tags.clone(),
+            partype: self.partype.clone(),
+            blurb: self.blurb_plain(),
+            image: self.image.clone(),
+        }
+    }
+}
+
+/// The fields [Champion] and [ChampionShort](super::champions::ChampionShort)
+/// have in common, and everything derived from them.
+pub trait ChampionData {
+    /// The short lore summary, suited to champion info panels.
+    fn blurb(&self) -> &str;
+
+    /// The champion's attack, defense, magic and difficulty ratings.
+    fn info(&self) -> &Info;
+
+    /// The champion's tags, primary tag first.
+    fn tags(&self) -> &[Tag];
+
+    /// The champion's resource as ddragon lists it, e.x. `Blood Well`.
+    fn partype(&self) -> &str;
+
+    /// The champion's base stats, keyed by their ddragon name.
+    fn stats(&self) -> &HashMap<String, f64>;
+
+    /// The short lore summary with any markup removed.
+    fn blurb_plain(&self) -> String {
+        strip_html(self.blurb())
+    }
+
+    /// The value of one of this champion's base stats, if it's listed.
+    fn stat(&self, stat: ChampionStat) -> Option<f64> {
+        self.stats().get(stat.key()).copied()
+    }
+
+    /// The resource this champion's abilities use, parsed from `partype`.
+    fn resource_type(&self) -> ResourceType {
+        ResourceType::from_partype(self.partype())
+    }
+
+    /// Whether the champion's base attack range is above
+    /// [RANGED_ATTACK_RANGE]. `false` if the stat isn't listed.
+    fn is_ranged(&self) -> bool {
+        self.stat(ChampionStat::AttackRange).is_some_and(|range| range > RANGED_ATTACK_RANGE)
+    }
+
+    /// A rough guess at the lanes this champion plays, most likely first,
+    /// based on its tags and attack range using [LANE_HINTS]. Meant as a
+    /// starting point for draft tools, not as real play data.
+    fn likely_lanes(&self) -> Vec<Lane> {
+        self.likely_lanes_with(LANE_HINTS)
+    }
+
+    /// Like [likely_lanes](Self::likely_lanes), but with your own table of
+    /// lanes per tag instead of [LANE_HINTS].
+    fn likely_lanes_with(&self, hints: &[(Tag, &[Lane])]) -> Vec<Lane> {
+        likely_lanes(self.tags(), self.is_ranged(), hints)
+    }
+
+    /// The champion's resource together with its base and per-level values,
+    /// as a resource bar needs them. `None` for champions without a resource.
+    fn resource_summary(&self) -> Option<ResourceSummary> {
+        ResourceSummary::new(self.resource_type(), self.stats())
+    }
+}
+
+macro_rules! champion_data {
+    ($s:ident) => {
+        impl $crate::models::champion::ChampionData for $s {
+            fn blurb(&self) -> &str {
+                &self.blurb
+            }
+            fn info(&self) -> &$crate::models::champion::Info {
+                &self.info
+            }
+            fn tags(&self) -> &[$crate::models::champion::Tag] {
+                &self.tags
+            }
+            fn partype(&self) -> &str {
+                &self.partype
+            }
+            fn stats(&self) -> &std::collections::HashMap<String, f64> {
+                &self.stats
+            }
+        }
+    };
+}
+pub(crate) use champion_data;
+
+champion_data!(Champion);
+
+/// The display fields of a [Champion], as returned by [Champion::summary].
+/// The blurb has its markup removed, and the square portrait URL can be
+/// built from the image with a client's `image_url`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct ChampionSummary {
+    pub id: String,
+    pub name: String,
+    pub title: String,
+    pub tags: Vec<Tag>,
+    pub partype: String,
+    pub blurb: String,
+    pub image: Image,
+}
+
+has_image!(ChampionSummary);
+
+/// A trimmed view of a [Champion] for re-serializing, e.x. when proxying
+/// ddragon data to a frontend, with only its id, name, title, tags and image.
+/// It borrows from the champion rather than copying it.
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChampionLite<'a> {
+    pub id: &'a str,
+    pub name: &'a str,
+    pub title: &'a str,
+    pub tags: &'a [Tag],
+    pub image: &'a Image,
+}
+
+impl<'a> From<&'a Champion> for ChampionLite<'a> {
+    fn from(champion: &'a Champion) -> Self {
+        Self {
+            id: &champion.id,
+            name: &champion.name,
+            title: &champion.title,
+            tags: &champion.tags,
+            image: &champion.image,
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 pub struct Info {
+    #[serde(deserialize_with = "number_from_string")]
     pub attack: i64,
+    #[serde(deserialize_with = "number_from_string")]
     pub defense: i64,
+    #[serde(deserialize_with = "number_from_string")]
     pub magic: i64,
+    #[serde(deserialize_with = "number_from_string")]
     pub difficulty: i64,
 }
 
+/// The highest rating ddragon gives in any [Info] category.
+pub const MAX_RATING: u8 = 10;
+
+/// The base attack range above which a champion counts as ranged. Melee
+/// champions sit between 125 and 175, and the shortest ranged ones at 325.
+/// Shapeshifters are classified by the form ddragon lists, e.x. Jayce is
+/// melee and Nidalee is ranged, and Kayle counts as melee since she only
+/// becomes ranged later in the game.
+pub const RANGED_ATTACK_RANGE: f64 = 200.0;
+
+impl Info {
+    /// The attack rating, clamped to `0..=10`.
+    pub fn attack(&self) -> u8 {
+        clamp_rating(self.attack)
+    }
+
+    /// The defense rating, clamped to `0..=10`.
+    pub fn defense(&self) -> u8 {
+        clamp_rating(self.defense)
+    }
+
+    /// The magic rating, clamped to `0..=10`.
+    pub fn magic(&self) -> u8 {
+        clamp_rating(self.magic)
+    }
+
+    /// The difficulty rating, clamped to `0..=10`.
+    pub fn difficulty(&self) -> u8 {
+        clamp_rating(self.difficulty)
+    }
+
+    /// Whether every rating is within the documented `0..=10` range.
+    pub fn is_valid(&self) -> bool {
+        [self.attack, self.defense, self.magic, self.difficulty]
+            .iter()
+            .all(|rating| (0..=i64::from(MAX_RATING)).contains(rating))
+    }
+}
+
+fn clamp_rating(rating: i64) -> u8 {
+    rating.clamp(0, i64::from(MAX_RATING)) as u8
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 pub struct Passive {
     pub name: String,
@@ -49,9 +296,79 @@ pub struct Passive {
 
 has_image!(Passive);
 
+/// One entry of a champion's ability bar, as returned by
+/// [Champion::abilities].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Ability<'a> {
+    Passive(&'a Passive),
+    Active(&'a Spell),
+}
+
+impl Ability<'_> {
+    /// The ability's display name, in the locale the data was fetched in.
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Passive(passive) => &passive.name,
+            Self::Active(spell) => &spell.name,
+        }
+    }
+}
+
+impl HasImage for Ability<'_> {
+    fn image_path(&self) -> String {
+        match self {
+            Self::Passive(passive) => passive.image_path(),
+            Self::Active(spell) => spell.image_path(),
+        }
+    }
+
+    fn sprite_path(&self) -> String {
+        match self {
+            Self::Passive(passive) => passive.sprite_path(),
+            Self::Active(spell) => spell.sprite_path(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct RecommendedSet {
+    #[serde(default)]
+    pub champion: String,
+    #[serde(default)]
+    pub title: String,
+    #[serde(default)]
+    pub map: String,
+    #[serde(default)]
+    pub mode: String,
+    #[serde(rename = "type", default)]
+    pub kind: String,
+    #[serde(default)]
+    pub blocks: Vec<RecommendedBlock>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct RecommendedBlock {
+    #[serde(rename = "type")]
+    pub kind: String,
+    #[serde(rename = "recMath", default)]
+    pub rec_math: bool,
+    #[serde(default)]
+    pub items: Vec<RecommendedItem>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct RecommendedItem {
+    pub id: String,
+    #[serde(deserialize_with = "number_from_string")]
+    pub count: i64,
+    #[serde(rename = "hideCount", default)]
+    pub hide_count: bool,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 pub struct Skin {
     pub id: String,
+    #[serde(deserialize_with = "number_from_string")]
     pub num: i64,
     pub name: String,
     pub chromas: bool,
@@ -63,7 +380,9 @@ pub struct Spell {
     pub name: String,
     pub description: String,
     pub tooltip: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub leveltip: Option<LevelTip>,
+    #[serde(deserialize_with = "number_from_string")]
     pub maxrank: i64,
     pub cooldown: Vec<f64>,
     #[serde(rename = "cooldownBurn")]
@@ -76,22 +395,229 @@ pub struct Spell {
     pub effect_burn: Vec<Option<String>>,
     #[serde(rename = "costType")]
     pub cost_type: String,
+    #[serde(default)]
+    #[serde(deserialize_with = "string_from_any")]
     pub maxammo: String,
     pub range: Vec<i64>,
     #[serde(rename = "rangeBurn")]
     pub range_burn: String,
     pub image: Image,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub resource: Option<String>,
+    #[serde(default)]
+    pub vars: Vec<SpellVar>,
 }
 
 has_image!(Spell);
 
+impl Spell {
+    /// The description with any markup removed.
+    pub fn description_plain(&self) -> String {
+        strip_html(&self.description)
+    }
+
+    /// The description with its markup converted to Markdown, as described
+    /// in [html_to_markdown].
+    pub fn description_markdown(&self) -> String {
+        html_to_markdown(&self.description)
+    }
+
+    /// The tooltip with any markup removed. Placeholders such as `{{ e1 }}`
+    /// are left as-is.
+    pub fn tooltip_plain(&self) -> String {
+        strip_html(&self.tooltip)
+    }
+
+    /// The tooltip with its markup converted to Markdown, as described in
+    /// [html_to_markdown]. Placeholders such as `{{ e1 }}` are left as-is.
+    pub fn tooltip_markdown(&self) -> String {
+        html_to_markdown(&self.tooltip)
+    }
+
+    /// The per-rank values of `cooldownBurn`, with `None` for ranks that
+    /// aren't numeric.
+    pub fn cooldown_burn_parsed(&self) -> Vec<Option<f64>> {
+        parse_burn(&self.cooldown_burn)
+    }
+
+    /// The per-rank values of `costBurn`, with `None` for ranks that
+    /// aren't numeric.
+    pub fn cost_burn_parsed(&self) -> Vec<Option<f64>> {
+        parse_burn(&self.cost_burn)
+    }
+
+    /// The per-rank values of `rangeBurn`, with `None` for ranks that
+    /// aren't numeric.
+    pub fn range_burn_parsed(&self) -> Vec<Option<f64>> {
+        parse_burn(&self.range_burn)
+    }
+
+    /// Per-rank cooldowns, preferring the display-accurate burn values over
+    /// the numeric array when the two disagree. Ranks with no numeric value
+    /// are `None`.
+    pub fn cooldowns(&self) -> Vec<Option<f64>> {
+        reconcile_burn(&self.cooldown_burn_parsed(), &self.cooldown)
+    }
+
+    /// Per-rank costs, preferring the display-accurate burn values over the
+    /// numeric array when the two disagree. Ranks with no numeric value are
+    /// `None`.
+    pub fn costs(&self) -> Vec<Option<f64>> {
+        let cost = self.cost.iter().map(|&c| c as f64).collect::<Vec<_>>();
+        reconcile_burn(&self.cost_burn_parsed(), &cost)
+    }
+
+    /// The spell's per-rank cost together with the resource it's paid in.
+    /// Spells whose `costType` is the `{{ abilityresourcename }}` placeholder
+    /// cost `ability_resource`, usually the champion's
+    /// [resource_type](Champion::resource_type), and `No Cost` spells cost
+    /// [ResourceType::None].
+    pub fn cost_in(&self, ability_resource: &ResourceType) -> SpellCost {
+        let cost_type = self.cost_type.trim();
+        let resource = if cost_type.contains("abilityresourcename") {
+            ability_resource.clone()
+        } else if cost_type == "No Cost" {
+            ResourceType::None
+        } else {
+            ResourceType::from_partype(cost_type)
+        };
+        SpellCost { resource, per_rank: self.cost.clone() }
+    }
+
+    /// How many charges the spell can hold, for abilities cast from stored
+    /// ammo. `None` for ordinary spells, which ddragon marks with `-1`.
+    pub fn max_charges(&self) -> Option<u32> {
+        self.maxammo.trim().parse::<u32>().ok().filter(|&charges| charges > 0)
+    }
+
+    /// Whether the spell is cast from stored charges rather than a plain
+    /// cooldown.
+    pub fn has_charges(&self) -> bool {
+        self.max_charges().is_some()
+    }
+
+    /// Per-rank ranges, preferring the display-accurate burn values over the
+    /// numeric array when the two disagree. Ranks with no numeric value are
+    /// `None`.
+    pub fn ranges(&self) -> Vec<Option<f64>> {
+        let range = self.range.iter().map(|&r| r as f64).collect::<Vec<_>>();
+        reconcile_burn(&self.range_burn_parsed(), &range)
+    }
+
+    /// The cost, cooldown and range of each rank, zipped together from
+    /// [costs](Self::costs), [cooldowns](Self::cooldowns) and
+    /// [ranges](Self::ranges). There is one entry per rank up to `maxrank`.
+    /// Values listed only once apply to every rank, and ranks a list doesn't
+    /// cover are `None`.
+    pub fn ranks(&self) -> Vec<SpellRankData> {
+        let (costs, cooldowns, ranges) = (self.costs(), self.cooldowns(), self.ranges());
+        let rank_count = match usize::try_from(self.maxrank) {
+            Ok(maxrank) if maxrank > 0 => maxrank,
+            _ => costs.len().max(cooldowns.len()).max(ranges.len()),
+        };
+        let at = |values: &[Option<f64>], index: usize| match values {
+            [value] => *value,
+            _ => values.get(index).copied().flatten(),
+        };
+
+        (0..rank_count)
+            .map(|index| SpellRankData {
+                rank: index + 1,
+                cost: at(&costs, index),
+                cooldown: at(&cooldowns, index),
+                range: at(&ranges, index),
+            })
+            .collect()
+    }
+
+    /// The spell's cooldown at the given rank, counting from 1, after
+    /// `haste` ability haste, i.e. `cooldown / (1 + haste / 100)`. A single
+    /// listed cooldown applies to every rank.
+    pub fn cooldown_with_haste(&self, rank: usize, haste: f64) -> Option<f64> {
+        let cooldown = match (rank, self.cooldowns().as_slice()) {
+            (0, _) => None,
+            (_, [cooldown]) => *cooldown,
+            (_, cooldowns) => cooldowns.get(rank - 1).copied().flatten(),
+        }?;
+        Some(cooldown / (1.0 + haste / 100.0))
+    }
+
+    /// The value of effect `e<effect_index>` at the given rank, counting from
+    /// 1, e.x. a damage spell's base damage. Index 0 is always empty, and an
+    /// effect with a single value applies to every rank.
+    pub fn effect_at(&self, effect_index: usize, rank: usize) -> Option<f64> {
+        effect_at(&self.effect, effect_index, rank)
+    }
+
+    /// The spell's range at the given rank, counting from 1. A single listed
+    /// range applies to every rank.
+    pub fn range_at(&self, rank: usize) -> Option<i64> {
+        match (rank, self.range.as_slice()) {
+            (0, _) => None,
+            (_, [range]) => Some(*range),
+            (_, ranges) => ranges.get(rank - 1).copied(),
+        }
+    }
+
+    /// The spell's range at `maxrank`, or the last listed range if `maxrank`
+    /// doesn't match the data.
+    pub fn max_range(&self) -> Option<i64> {
+        usize::try_from(self.maxrank)
+            .ok()
+            .and_then(|maxrank| self.range_at(maxrank))
+            .or_else(|| self.range.last().copied())
+    }
+
+    /// The scaling ratio a tooltip placeholder refers to, e.x. `{{ a1 }}` or
+    /// just `a1`.
+    pub fn resolve_var(&self, placeholder: &str) -> Option<&SpellVar> {
+        let key = placeholder.trim().trim_start_matches("{{").trim_end_matches("}}").trim();
+        self.vars.iter().find(|var| var.key == key)
+    }
+
+    /// The label and effect of each line of the spell's
+    /// [leveltip](Self::leveltip), describing what improves per rank. Empty
+    /// if the spell has no leveltip.
+    pub fn level_tips(&self) -> Vec<(&str, &str)> {
+        self.leveltip.as_ref().map(LevelTip::entries).unwrap_or_default()
+    }
+}
+
+/// A scaling ratio used in a [Spell]'s tooltip, e.x. a coefficient of `0.6`
+/// linked to `spelldamage` for 60% AP. `coeff` has one entry per rank, or a
+/// single entry that applies to every rank.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct SpellVar {
+    pub link: String,
+    #[serde(deserialize_with = "one_or_many")]
+    pub coeff: Vec<f64>,
+    pub key: String,
+}
+
+/// A single rank of a [Spell], as returned by [Spell::ranks].
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct SpellRankData {
+    /// The rank, starting from 1.
+    pub rank: usize,
+    pub cost: Option<f64>,
+    pub cooldown: Option<f64>,
+    pub range: Option<f64>,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 pub struct LevelTip {
     pub label: Vec<String>,
     pub effect: Vec<String>,
 }
 
+impl LevelTip {
+    /// Pairs each label, e.x. `Cooldown`, with its effect template, e.x.
+    /// `{{ cooldown }} -> {{ cooldownNL }}`. Unpaired entries are skipped.
+    pub fn entries(&self) -> Vec<(&str, &str)> {
+        self.label.iter().zip(&self.effect).map(|(l, e)| (l.as_str(), e.as_str())).collect()
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Tag {
     Assassin,
@@ -101,3 +627,831 @@ pub enum Tag {
     Support,
     Tank,
 }
+
+/// A champion's class for display, e.x. on a filter chip, converted from a
+/// [Tag] or parsed from a raw tag with [from_tag](Self::from_tag). Tags this
+/// enum doesn't know about yet are kept as [Other](Self::Other).
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
+#[serde(from = "String", into = "String")]
+pub enum ChampionTag {
+    Assassin,
+    Fighter,
+    Mage,
+    Marksman,
+    Support,
+    Tank,
+    Other(String),
+}
+
+impl ChampionTag {
+    /// Parses a tag as ddragon lists it, e.x. `Marksman`.
+    pub fn from_tag(tag: &str) -> Self {
+        match tag {
+            "Assassin" => Self::Assassin,
+            "Fighter" => Self::Fighter,
+            "Mage" => Self::Mage,
+            "Marksman" => Self::Marksman,
+            "Support" => Self::Support,
+            "Tank" => Self::Tank,
+            other => Self::Other(other.to_owned()),
+        }
+    }
+
+    /// The tag as ddragon lists it, which also reads well as a display name.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Assassin => "Assassin",
+            Self::Fighter => "Fighter",
+            Self::Mage => "Mage",
+            Self::Marksman => "Marksman",
+            Self::Support => "Support",
+            Self::Tank => "Tank",
+            Self::Other(tag) => tag,
+        }
+    }
+
+    /// An emoji suited to labelling this class on a filter chip, e.x. `🏹`
+    /// for marksmen. Unknown tags get a neutral `🏷️`.
+    pub fn icon_hint(&self) -> &'static str {
+        match self {
+            Self::Assassin => "🗡️",
+            Self::Fighter => "⚔️",
+            Self::Mage => "🔮",
+            Self::Marksman => "🏹",
+            Self::Support => "✨",
+            Self::Tank => "🛡️",
+            Self::Other(_) => "🏷️",
+        }
+    }
+}
+
+impl fmt::Display for ChampionTag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<String> for ChampionTag {
+    fn from(tag: String) -> Self {
+        Self::from_tag(&tag)
+    }
+}
+
+impl From<ChampionTag> for String {
+    fn from(tag: ChampionTag) -> Self {
+        match tag {
+            ChampionTag::Other(tag) => tag,
+            tag => tag.as_str().to_owned(),
+        }
+    }
+}
+
+impl From<Tag> for ChampionTag {
+    fn from(tag: Tag) -> Self {
+        match tag {
+            Tag::Assassin => Self::Assassin,
+            Tag::Fighter => Self::Fighter,
+            Tag::Mage => Self::Mage,
+            Tag::Marksman => Self::Marksman,
+            Tag::Support => Self::Support,
+            Tag::Tank => Self::Tank,
+        }
+    }
+}
+
+/// A position on Summoner's Rift, as guessed by `likely_lanes`.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Lane {
+    Top,
+    Jungle,
+    Mid,
+    Bot,
+    Support,
+}
+
+/// The lanes each [Tag] usually plays, most common first, used by
+/// `likely_lanes`. This is a rough starting point rather than real play
+/// data; pass your own table to `likely_lanes_with` to override it.
+pub const LANE_HINTS: &[(Tag, &[Lane])] = &[
+    (Tag::Marksman, &[Lane::Bot]),
+    (Tag::Support, &[Lane::Support]),
+    (Tag::Mage, &[Lane::Mid, Lane::Support]),
+    (Tag::Assassin, &[Lane::Mid, Lane::Jungle]),
+    (Tag::Fighter, &[Lane::Top, Lane::Jungle]),
+    (Tag::Tank, &[Lane::Top, Lane::Support, Lane::Jungle]),
+];
+
+/// Looks up the lanes for each of `tags` in `hints`, primary tag first,
+/// without duplicates. Melee champions are never guessed into
+/// [Lane::Bot], since bot lane carries are ranged.
+pub(crate) fn likely_lanes(tags: &[Tag], ranged: bool, hints: &[(Tag, &[Lane])]) -> Vec<Lane> {
+    let mut lanes = Vec::new();
+    for tag in tags {
+        let tag_lanes = hints.iter().filter(|(hint, _)| hint == tag).flat_map(|(_, lanes)| *lanes);
+        for &lane in tag_lanes {
+            if !lanes.contains(&lane) && (ranged || lane != Lane::Bot) {
+                lanes.push(lane);
+            }
+        }
+    }
+    lanes
+}
+
+/// The resource a champion's abilities use, parsed from its `partype` with
+/// [from_partype](Self::from_partype). Resources this enum doesn't know about
+/// yet are kept as [Other](Self::Other).
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
+#[serde(from = "String", into = "String")]
+pub enum ResourceType {
+    Mana,
+    Energy,
+    Rage,
+    Fury,
+    Ferocity,
+    Heat,
+    Shield,
+    BloodWell,
+    Flow,
+    Courage,
+    Grit,
+    Health,
+    /// The champion uses no resource.
+    None,
+    Other(String),
+}
+
+impl ResourceType {
+    /// Parses a `partype` as ddragon lists it, e.x. `Mana` or `Blood Well`.
+    /// An empty `partype` is treated as [None](Self::None).
+    pub fn from_partype(partype: &str) -> Self {
+        match partype {
+            "Mana" => Self::Mana,
+            "Energy" => Self::Energy,
+            "Rage" => Self::Rage,
+            "Fury" => Self::Fury,
+            "Ferocity" => Self::Ferocity,
+            "Heat" => Self::Heat,
+            "Shield" => Self::Shield,
+            "Blood Well" => Self::BloodWell,
+            "Flow" => Self::Flow,
+            "Courage" => Self::Courage,
+            "Grit" => Self::Grit,
+            "Health" => Self::Health,
+            "None" | "" => Self::None,
+            other => Self::Other(other.to_owned()),
+        }
+    }
+
+    /// The resource as ddragon lists it in `partype`, e.x. `Blood Well`.
+    pub fn as_partype(&self) -> &str {
+        match self {
+            Self::Mana => "Mana",
+            Self::Energy => "Energy",
+            Self::Rage => "Rage",
+            Self::Fury => "Fury",
+            Self::Ferocity => "Ferocity",
+            Self::Heat => "Heat",
+            Self::Shield => "Shield",
+            Self::BloodWell => "Blood Well",
+            Self::Flow => "Flow",
+            Self::Courage => "Courage",
+            Self::Grit => "Grit",
+            Self::Health => "Health",
+            Self::None => "None",
+            Self::Other(partype) => partype,
+        }
+    }
+
+    /// A conventional hex color for drawing this resource's bar, e.x.
+    /// `#3b82f6` for mana. Unknown resources get a neutral grey.
+    pub fn color_hint(&self) -> &'static str {
+        match self {
+            Self::Mana => "#3b82f6",
+            Self::Energy => "#facc15",
+            Self::Rage | Self::Fury => "#dc2626",
+            Self::Ferocity => "#f97316",
+            Self::Heat => "#ea580c",
+            Self::Shield => "#d1d5db",
+            Self::BloodWell => "#991b1b",
+            Self::Flow => "#38bdf8",
+            Self::Courage => "#d97706",
+            Self::Grit => "#78716c",
+            Self::Health => "#16a34a",
+            Self::None | Self::Other(_) => "#6b7280",
+        }
+    }
+}
+
+impl From<String> for ResourceType {
+    fn from(partype: String) -> Self {
+        Self::from_partype(&partype)
+    }
+}
+
+impl From<ResourceType> for String {
+    fn from(resource: ResourceType) -> Self {
+        match resource {
+            ResourceType::Other(partype) => partype,
+            resource => resource.as_partype().to_owned(),
+        }
+    }
+}
+
+/// A champion's resource and the stats describing its bar, as returned by
+/// `resource_summary`. Stats missing from the data are treated as zero.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ResourceSummary {
+    pub resource: ResourceType,
+    pub base: f64,
+    pub per_level: f64,
+    pub regen: f64,
+    pub regen_per_level: f64,
+}
+
+impl ResourceSummary {
+    pub(crate) fn new(resource: ResourceType, stats: &HashMap<String, f64>) -> Option<Self> {
+        if resource == ResourceType::None {
+            return None;
+        }
+        let stat = |stat: ChampionStat| stats.get(stat.key()).copied().unwrap_or_default();
+        Some(Self {
+            resource,
+            base: stat(ChampionStat::Mp),
+            per_level: stat(ChampionStat::MpPerLevel),
+            regen: stat(ChampionStat::MpRegen),
+            regen_per_level: stat(ChampionStat::MpRegenPerLevel),
+        })
+    }
+
+    /// The size of the resource bar at the given champion level (1-18).
+    pub fn max_at_level(&self, level: u8) -> f64 {
+        stat_at_level(self.base, self.per_level, level)
+    }
+
+    /// The resource regenerated every 5 seconds at the given champion level.
+    pub fn regen_at_level(&self, level: u8) -> f64 {
+        stat_at_level(self.regen, self.regen_per_level, level)
+    }
+}
+
+/// Applies League's per-level stat growth curve, which grows slightly faster
+/// at higher levels than a straight line.
+fn stat_at_level(base: f64, growth: f64, level: u8) -> f64 {
+    let levels = f64::from(level.max(1) - 1);
+    base + growth * levels * (0.7025 + 0.0175 * levels)
+}
+
+/// A champion's total stats at a given level with a set of items, as
+/// returned by [combine_champion_and_items]. Attack speed is in attacks per
+/// second, regeneration is per 5 seconds, and crit chance and life steal are
+/// fractions, e.x. `0.25` for 25%.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Default)]
+pub struct ComputedStats {
+    pub hp: f64,
+    pub mp: f64,
+    pub armor: f64,
+    pub magic_resist: f64,
+    pub attack_damage: f64,
+    pub ability_power: f64,
+    pub attack_speed: f64,
+    pub move_speed: f64,
+    pub attack_range: f64,
+    pub crit_chance: f64,
+    pub life_steal: f64,
+    pub hp_regen: f64,
+    pub mp_regen: f64,
+}
+
+/// Adds the `stats` of the given items onto a champion's base stats at a
+/// level (1-18). Flat modifiers, e.x. `FlatArmorMod`, are added to the grown
+/// base stat, and the matching percent modifiers, e.x. `PercentHPPoolMod`,
+/// then scale the total. `PercentAttackSpeedMod` is bonus attack speed, so it
+/// is added to the per-level bonus before scaling the base attack speed.
+/// Stats missing from the data are treated as zero, and crit chance is capped
+/// at 100%.
+pub fn combine_champion_and_items(
+    champion: &Champion,
+    level: u8,
+    items: &[&Item],
+) -> ComputedStats {
+    let base = |stat: ChampionStat| champion.stat(stat).unwrap_or_default();
+    let grown =
+        |stat: ChampionStat, growth: ChampionStat| stat_at_level(base(stat), base(growth), level);
+    let bonus = |key: &str| items.iter().filter_map(|item| item.stats.get(key)).sum::<f64>();
+    let scale = |key: &str| 1.0 + bonus(key);
+
+    let bonus_attack_speed = stat_at_level(0.0, base(ChampionStat::AttackSpeedPerLevel), level)
+        / 100.0
+        + bonus("PercentAttackSpeedMod");
+    ComputedStats {
+        hp: (grown(ChampionStat::Hp, ChampionStat::HpPerLevel) + bonus("FlatHPPoolMod"))
+            * scale("PercentHPPoolMod"),
+        mp: (grown(ChampionStat::Mp, ChampionStat::MpPerLevel) + bonus("FlatMPPoolMod"))
+            * scale("PercentMPPoolMod"),
+        armor: (grown(ChampionStat::Armor, ChampionStat::ArmorPerLevel) + bonus("FlatArmorMod"))
+            * scale("PercentArmorMod"),
+        magic_resist: (grown(ChampionStat::SpellBlock, ChampionStat::SpellBlockPerLevel)
+            + bonus("FlatSpellBlockMod"))
+            * scale("PercentSpellBlockMod"),
+        attack_damage: (grown(ChampionStat::AttackDamage, ChampionStat::AttackDamagePerLevel)
+            + bonus("FlatPhysicalDamageMod"))
+            * scale("PercentPhysicalDamageMod"),
+        ability_power: bonus("FlatMagicDamageMod") * scale("PercentMagicDamageMod"),
+        attack_speed: base(ChampionStat::AttackSpeed) * (1.0 + bonus_attack_speed),
+        move_speed: (base(ChampionStat::MoveSpeed) + bonus("FlatMovementSpeedMod"))
+            * scale("PercentMovementSpeedMod"),
+        attack_range: base(ChampionStat::AttackRange),
+        crit_chance: (grown(ChampionStat::Crit, ChampionStat::CritPerLevel)
+            + bonus("FlatCritChanceMod"))
+        .min(1.0),
+        life_steal: bonus("PercentLifeStealMod"),
+        hp_regen: grown(ChampionStat::HpRegen, ChampionStat::HpRegenPerLevel)
+            + bonus("FlatHPRegenMod"),
+        mp_regen: grown(ChampionStat::MpRegen, ChampionStat::MpRegenPerLevel)
+            + bonus("FlatMPRegenMod"),
+    }
+}
+
+/// What a spell costs to cast at each rank, as returned by
+/// [Spell::cost_in], e.x. 60/65/70/75/80 [Mana](ResourceType::Mana).
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct SpellCost {
+    pub resource: ResourceType,
+    pub per_rank: Vec<i64>,
+}
+
+/// The key an ability is bound to, matching its position in a champion's
+/// `spells` list.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SpellSlot {
+    Q,
+    W,
+    E,
+    R,
+}
+
+impl SpellSlot {
+    /// Every slot, in the order abilities are listed.
+    pub const ALL: [SpellSlot; 4] = [Self::Q, Self::W, Self::E, Self::R];
+
+    /// The key this slot is cast with on the default keybindings.
+    pub fn hotkey(&self) -> char {
+        match self {
+            Self::Q => 'Q',
+            Self::W => 'W',
+            Self::E => 'E',
+            Self::R => 'R',
+        }
+    }
+
+    /// The index of this slot in a champion's `spells` list.
+    pub fn index(&self) -> usize {
+        match self {
+            Self::Q => 0,
+            Self::W => 1,
+            Self::E => 2,
+            Self::R => 3,
+        }
+    }
+}
+
+/// The base stats listed in a champion's `stats` map.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum ChampionStat {
+    Hp,
+    HpPerLevel,
+    Mp,
+    MpPerLevel,
+    MoveSpeed,
+    Armor,
+    ArmorPerLevel,
+    SpellBlock,
+    SpellBlockPerLevel,
+    AttackRange,
+    HpRegen,
+    HpRegenPerLevel,
+    MpRegen,
+    MpRegenPerLevel,
+    Crit,
+    CritPerLevel,
+    AttackDamage,
+    AttackDamagePerLevel,
+    AttackSpeedPerLevel,
+    AttackSpeed,
+}
+
+impl ChampionStat {
+    /// The key this stat is stored under in a champion's `stats` map.
+    pub fn key(&self) -> &'static str {
+        match self {
+            Self::Hp => "hp",
+            Self::HpPerLevel => "hpperlevel",
+            Self::Mp => "mp",
+            Self::MpPerLevel => "mpperlevel",
+            Self::MoveSpeed => "movespeed",
+            Self::Armor => "armor",
+            Self::ArmorPerLevel => "armorperlevel",
+            Self::SpellBlock => "spellblock",
+            Self::SpellBlockPerLevel => "spellblockperlevel",
+            Self::AttackRange => "attackrange",
+            Self::HpRegen => "hpregen",
+            Self::HpRegenPerLevel => "hpregenperlevel",
+            Self::MpRegen => "mpregen",
+            Self::MpRegenPerLevel => "mpregenperlevel",
+            Self::Crit => "crit",
+            Self::CritPerLevel => "critperlevel",
+            Self::AttackDamage => "attackdamage",
+            Self::AttackDamagePerLevel => "attackdamageperlevel",
+            Self::AttackSpeedPerLevel => "attackspeedperlevel",
+            Self::AttackSpeed => "attackspeed",
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test {
+    use super::*;
+
+    /// Builds a [Spell] from a minimal spell with empty values, overridden by
+    /// the fields in `overrides`.
+    pub(crate) fn spell(overrides: serde_json::Value) -> Spell {
+        let mut fields = serde_json::json!({
+            "id": "Spell",
+            "name": "",
+            "description": "",
+            "tooltip": "",
+            "maxrank": 5,
+            "cooldown": [],
+            "cooldownBurn": "",
+            "cost": [],
+            "costBurn": "",
+            "effect": [],
+            "effectBurn": [],
+            "costType": "",
+            "range": [],
+            "rangeBurn": "",
+            "image": {
+                "full": "Spell.png",
+                "sprite": "spell0.png",
+                "group": "spell",
+                "x": 0, "y": 0, "w": 48, "h": 48
+            }
+        });
+        if let (Some(fields), serde_json::Value::Object(overrides)) =
+            (fields.as_object_mut(), overrides)
+        {
+            fields.extend(overrides);
+        }
+        serde_json::from_value(fields).unwrap()
+    }
+
+    #[test]
+    fn recommended_sets_deserialize_per_map() {
+        let sets: Vec<RecommendedSet> = serde_json::from_str(
+            r#"[
+                {
+                    "champion": "Ahri",
+                    "title": "AhriSR",
+                    "map": "SR",
+                    "mode": "CLASSIC",
+                    "type": "riot",
+                    "blocks": [
+                        {
+                            "type": "starting",
+                            "recMath": false,
+                            "items": [
+                                { "id": "1056", "count": 1, "hideCount": false },
+                                { "id": "2003", "count": "2", "hideCount": true }
+                            ]
+                        },
+                        { "type": "essential", "items": [{ "id": "6655", "count": 1 }] }
+                    ]
+                },
+                {
+                    "champion": "Ahri",
+                    "title": "AhriHA",
+                    "map": "HA",
+                    "mode": "ARAM",
+                    "type": "riot",
+                    "blocks": [{ "type": "starting", "recMath": true, "items": [] }]
+                }
+            ]"#,
+        )
+        .unwrap();
+
+        assert_eq!(sets.iter().map(|set| set.map.as_str()).collect::<Vec<_>>(), ["SR", "HA"]);
+        assert_eq!(sets[0].blocks[0].items[1].count, 2);
+        assert!(sets[0].blocks[0].items[1].hide_count);
+        assert!(!sets[0].blocks[1].items[0].hide_count);
+        assert!(sets[1].blocks[0].rec_math);
+    }
+
+    #[test]
+    fn resource_type_parses_partype() {
+        assert_eq!(ResourceType::from_partype("Mana"), ResourceType::Mana);
+        assert_eq!(ResourceType::from_partype("Blood Well"), ResourceType::BloodWell);
+        assert_eq!(ResourceType::from_partype(""), ResourceType::None);
+        assert_eq!(
+            ResourceType::from_partype("Crimson Rush"),
+            ResourceType::Other("Crimson Rush".to_owned())
+        );
+        assert_eq!(ResourceType::Mana.color_hint(), "#3b82f6");
+
+        let resources: Vec<ResourceType> =
+            serde_json::from_str(r#"["Blood Well", "Crimson Rush", "None"]"#).unwrap();
+        assert_eq!(resources[0], ResourceType::BloodWell);
+        assert_eq!(
+            serde_json::to_string(&resources).unwrap(),
+            r#"["Blood Well","Crimson Rush","None"]"#
+        );
+    }
+
+    #[test]
+    fn info_ratings_are_clamped() {
+        let info: Info = serde_json::from_str(
+            r#"{ "attack": 7, "defense": "4", "magic": -1, "difficulty": 12 }"#,
+        )
+        .unwrap();
+
+        assert_eq!((info.attack(), info.defense()), (7, 4));
+        assert_eq!((info.magic(), info.difficulty()), (0, 10));
+        assert!(!info.is_valid());
+    }
+
+    #[test]
+    fn spell_ranks_zip_values_defensively() {
+        let spell = spell(serde_json::json!({
+            "maxrank": 3,
+            "cooldown": [7.0, 7.0, 7.0],
+            "cooldownBurn": "7",
+            "cost": [55, 65],
+            "costBurn": "55/65",
+            "range": [970, 970, 970],
+            "rangeBurn": "970"
+        }));
+
+        let ranks = spell.ranks();
+        assert_eq!(ranks.len(), 3);
+        assert_eq!(
+            ranks[0],
+            SpellRankData { rank: 1, cost: Some(55.0), cooldown: Some(7.0), range: Some(970.0) }
+        );
+        assert_eq!(ranks[2].cost, None);
+        assert_eq!(ranks[2].cooldown, Some(7.0));
+        assert!(spell.level_tips().is_empty());
+    }
+
+    #[test]
+    fn spell_ranks_keep_gaps_at_their_rank() {
+        let spell = spell(serde_json::json!({
+            "maxrank": 3,
+            "cooldownBurn": "10/x/8",
+            "cost": [40, 50, 60],
+            "costBurn": "40/50/60"
+        }));
+
+        let ranks = spell.ranks();
+        assert_eq!(
+            ranks[1],
+            SpellRankData { rank: 2, cost: Some(50.0), cooldown: None, range: None }
+        );
+        assert_eq!(ranks[2].cooldown, Some(8.0));
+        assert_eq!(ranks[2].cost, Some(60.0));
+    }
+
+    #[test]
+    fn spell_vars_resolve_placeholders() {
+        let spell = spell(serde_json::json!({
+            "tooltip": "Deals {{ e1 }} (+{{ a1 }}) magic damage.",
+            "vars": [
+                { "link": "spelldamage", "coeff": 0.75, "key": "a1" },
+                { "link": "bonusattackdamage", "coeff": [0.5, 0.6], "key": "a2" }
+            ]
+        }));
+
+        let a1 = spell.resolve_var("{{ a1 }}").unwrap();
+        assert_eq!((a1.link.as_str(), a1.coeff.as_slice()), ("spelldamage", &[0.75][..]));
+        assert_eq!(spell.resolve_var("a2").unwrap().coeff, [0.5, 0.6]);
+        assert_eq!(spell.resolve_var("{{ e1 }}"), None);
+    }
+
+    #[test]
+    fn spell_ranges_per_rank() {
+        let spell = |maxrank: i64, range: &[i64]| {
+            spell(serde_json::json!({ "maxrank": maxrank, "range": range }))
+        };
+
+        let growing = spell(3, &[600, 700, 800]);
+        assert_eq!(growing.range_at(0), None);
+        assert_eq!(growing.range_at(2), Some(700));
+        assert_eq!(growing.range_at(4), None);
+        assert_eq!(growing.max_range(), Some(800));
+
+        let constant = spell(5, &[25000]);
+        assert_eq!(constant.range_at(3), Some(25000));
+        assert_eq!(constant.max_range(), Some(25000));
+
+        assert_eq!(spell(5, &[600, 700]).max_range(), Some(700));
+        assert_eq!(spell(5, &[]).max_range(), None);
+    }
+
+    #[test]
+    fn spell_effects_per_rank() {
+        let spell = spell(serde_json::json!({
+            "effect": [null, [80, 120, 160, 200, 240], [0.5], null, [10, 20]]
+        }));
+
+        assert_eq!(spell.effect_at(0, 1), None);
+        assert_eq!(spell.effect_at(1, 0), None);
+        assert_eq!(spell.effect_at(1, 3), Some(160.0));
+        assert_eq!(spell.effect_at(2, 4), Some(0.5));
+        assert_eq!(spell.effect_at(3, 1), None);
+        assert_eq!(spell.effect_at(4, 3), None);
+        assert_eq!(spell.effect_at(9, 1), None);
+    }
+
+    #[test]
+    fn cooldown_with_haste_scales_per_rank() {
+        let spell = spell(serde_json::json!({
+            "maxrank": 3,
+            "cooldown": [12.0, 10.0, 8.0],
+            "cooldownBurn": "12/10/8"
+        }));
+
+        assert_eq!(spell.cooldown_with_haste(1, 0.0), Some(12.0));
+        assert_eq!(spell.cooldown_with_haste(3, 100.0), Some(4.0));
+        assert_eq!(spell.cooldown_with_haste(0, 20.0), None);
+        assert_eq!(spell.cooldown_with_haste(4, 20.0), None);
+    }
+
+    #[test]
+    fn cooldown_with_haste_skips_ranks_without_a_cooldown() {
+        let spell = spell(serde_json::json!({ "maxrank": 3, "cooldownBurn": "12/x/8" }));
+
+        assert_eq!(spell.cooldown_with_haste(1, 0.0), Some(12.0));
+        assert_eq!(spell.cooldown_with_haste(2, 0.0), None);
+        assert_eq!(spell.cooldown_with_haste(3, 0.0), Some(8.0));
+    }
+
+    #[test]
+    fn spell_costs_parse_the_same_as_summoner_spells() {
+        use crate::models::summoner_spells::SummonerSpell;
+
+        for (cost_type, resource) in
+            [("Mana", ResourceType::Mana), ("Energy", ResourceType::Energy)]
+        {
+            let spell = spell(serde_json::json!({
+                "cost": [50, 55, 60],
+                "costBurn": "50/55/60",
+                "costType": cost_type,
+                "resource": format!("{{{{ cost }}}} {cost_type}")
+            }));
+            // The same spell as it would appear in `summoner.json`.
+            let mut fields = serde_json::to_value(&spell).unwrap();
+            fields["key"] = "1".into();
+            fields["summonerLevel"] = 1.into();
+            let summoner_spell: SummonerSpell = serde_json::from_value(fields).unwrap();
+
+            assert_eq!(spell.costs(), [Some(50.0), Some(55.0), Some(60.0)]);
+            assert_eq!(
+                spell.cost_in(&ResourceType::None),
+                SpellCost { resource: resource.clone(), per_rank: vec![50, 55, 60] }
+            );
+            assert_eq!(summoner_spell.cost, [50, 55, 60]);
+            assert_eq!(ResourceType::from_partype(&summoner_spell.cost_type), resource);
+            assert_eq!(summoner_spell.resource, Some(format!("{{{{ cost }}}} {cost_type}")));
+        }
+    }
+
+    #[test]
+    fn spell_costs_resolve_their_resource() {
+        let spell = |cost_type: &str| {
+            spell(serde_json::json!({
+                "cost": [60, 65, 70, 75, 80],
+                "costBurn": "60/65/70/75/80",
+                "costType": cost_type
+            }))
+        };
+
+        let cost = spell(" {{ abilityresourcename }}").cost_in(&ResourceType::Mana);
+        assert_eq!(
+            cost,
+            SpellCost { resource: ResourceType::Mana, per_rank: vec![60, 65, 70, 75, 80] }
+        );
+        assert_eq!(spell(" Energy").cost_in(&ResourceType::Mana).resource, ResourceType::Energy);
+        assert_eq!(spell("No Cost").cost_in(&ResourceType::Mana).resource, ResourceType::None);
+    }
+
+    #[test]
+    fn spell_charges_parse_from_any_maxammo() {
+        let spell = |maxammo: serde_json::Value| spell(serde_json::json!({ "maxammo": maxammo }));
+
+        assert_eq!(spell(serde_json::json!("3")).max_charges(), Some(3));
+        assert_eq!(spell(serde_json::json!(2)).max_charges(), Some(2));
+        assert!(!spell(serde_json::json!("-1")).has_charges());
+        assert!(!spell(serde_json::json!(null)).has_charges());
+
+        let with_object = spell(serde_json::json!({ "charges": 2 }));
+        assert!(!with_object.has_charges());
+        assert_eq!(with_object.maxammo, r#"{"charges":2}"#);
+    }
+
+    #[cfg(feature = "test-fixtures")]
+    #[test]
+    fn champion_lite_serializes_only_display_fields() {
+        let champions =
+            crate::models::ChampionsFull::from_json(crate::fixtures::CHAMPIONS_FULL.as_bytes())
+                .unwrap();
+        let lite = ChampionLite::from(&champions.data["Ahri"]);
+
+        assert_eq!(
+            serde_json::to_value(lite).unwrap(),
+            serde_json::json!({
+                "id": "Ahri",
+                "name": "Ahri",
+                "title": "the Nine-Tailed Fox",
+                "tags": ["Mage", "Assassin"],
+                "image": {
+                    "full": "Ahri.png",
+                    "sprite": "champion0.png",
+                    "group": "champion",
+                    "x": 48, "y": 0, "w": 48, "h": 48
+                }
+            })
+        );
+    }
+
+    #[cfg(feature = "test-fixtures")]
+    #[test]
+    fn items_add_onto_stats_at_level() {
+        use crate::fixtures::{CHAMPIONS_FULL, ITEMS};
+        use crate::models::{ChampionsFull, Items};
+
+        let champions = ChampionsFull::from_json(CHAMPIONS_FULL.as_bytes()).unwrap();
+        let items = Items::from_json(ITEMS.as_bytes()).unwrap();
+        let ahri = &champions.data["Ahri"];
+        let greaves = items.data.values().find(|item| item.name == "Berserker's Greaves").unwrap();
+
+        let bare = combine_champion_and_items(ahri, 1, &[]);
+        assert_eq!(bare.hp, 590.0);
+        assert_eq!(bare.move_speed, 330.0);
+        assert_eq!(bare.attack_speed, 0.668);
+
+        let geared = combine_champion_and_items(ahri, 18, &[greaves]);
+        assert_eq!(geared.hp, 590.0 + 104.0 * 17.0);
+        assert_eq!(geared.move_speed, 375.0);
+        assert!((geared.attack_speed - 0.668 * (1.0 + 0.374 + 0.35)).abs() < 1e-9);
+        assert_eq!(geared.ability_power, 0.0);
+    }
+
+    #[cfg(feature = "test-fixtures")]
+    #[test]
+    fn abilities_list_the_passive_first() {
+        let champions =
+            crate::models::ChampionsFull::from_json(crate::fixtures::CHAMPIONS_FULL.as_bytes())
+                .unwrap();
+        let abilities = champions.data["Ahri"].abilities();
+
+        let names: Vec<&str> = abilities.iter().map(Ability::name).collect();
+        assert_eq!(names, ["Essence Theft", "Orb of Deception"]);
+        assert!(matches!(abilities[0], Ability::Passive(_)));
+        assert_eq!(abilities[1].image_path(), "spell/AhriQ.png");
+    }
+
+    #[test]
+    fn spell_slots_map_to_hotkeys_in_order() {
+        let hotkeys: String = SpellSlot::ALL.iter().map(SpellSlot::hotkey).collect();
+        assert_eq!(hotkeys, "QWER");
+        assert!(SpellSlot::ALL.iter().enumerate().all(|(index, slot)| slot.index() == index));
+    }
+
+    #[test]
+    fn tags_parse_with_a_fallback() {
+        let tags: Vec<ChampionTag> = serde_json::from_str(r#"["Marksman", "Slayer"]"#).unwrap();
+        assert_eq!(tags, [ChampionTag::Marksman, ChampionTag::Other("Slayer".to_owned())]);
+        assert_eq!(ChampionTag::from(Tag::Marksman), tags[0]);
+        assert_eq!(tags[0].to_string(), "Marksman");
+        assert_eq!(tags[1].icon_hint(), "🏷️");
+        assert_eq!(serde_json::to_string(&tags).unwrap(), r#"["Marksman","Slayer"]"#);
+    }
+
+    #[test]
+    fn level_tips_pair_labels_with_effects() {
+        let leveltip: LevelTip = serde_json::from_str(
+            r#"{
+                "label": ["Damage", "Cooldown", "@AbilityResourceName@ Cost"],
+                "effect": ["{{ basedamage }} -> {{ basedamageNL }}", "{{ cooldown }} -> {{ cooldownNL }}"]
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            leveltip.entries(),
+            [
+                ("Damage", "{{ basedamage }} -> {{ basedamageNL }}"),
+                ("Cooldown", "{{ cooldown }} -> {{ cooldownNL }}")
+            ]
+        );
+    }
+}
@@ -2,15 +2,293 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 use super::{
-    champion::{Info, Tag},
-    shared::{has_image, Image},
+    champion::{
+        champion_data, ChampionData, ChampionStat, Info, ResourceType, Tag, RANGED_ATTACK_RANGE,
+    },
+    shared::{
+        diff_data_by, from_json, get_ignore_case, has_data, has_image, has_images, DataDiff,
+        DataMap, Image,
+    },
 };
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 pub struct Champions {
     pub format: String,
     pub version: String,
-    pub data: HashMap<String, ChampionShort>,
+    pub data: DataMap<ChampionShort>,
+}
+
+has_data!(Champions);
+has_images!(Champions);
+from_json!(Champions);
+
+impl Champions {
+    /// Returns the champion with the given id. If there's no exact match, the
+    /// id is matched case-insensitively instead, which covers ids whose casing
+    /// has changed over time (e.g. `FiddleSticks` vs. `Fiddlesticks`).
+    pub fn get(&self, id: &str) -> Option<&ChampionShort> {
+        get_ignore_case(&self.data, id)
+    }
+
+    /// Picks a champion uniformly at random, or `None` if there are no
+    /// champions.
+    #[cfg(feature = "rand")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+    pub fn random(&self) -> Option<&ChampionShort> {
+        self.random_with(&mut rand::thread_rng())
+    }
+
+    /// Picks a champion uniformly at random using the given [rand::Rng]. With
+    /// a seeded generator the same champion is picked every time for the same
+    /// data, which makes this suitable for tests.
+    #[cfg(feature = "rand")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+    pub fn random_with<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Option<&ChampionShort> {
+        use rand::seq::SliceRandom;
+
+        // Map iteration order differs between runs, so pick from sorted ids
+        // to keep seeded picks stable.
+        let mut ids: Vec<&String> = self.data.keys().collect();
+        ids.sort_unstable();
+        ids.choose(rng).map(|id| &self.data[*id])
+    }
+
+    /// Returns the data key (the champion's id) for a display name, e.g.
+    /// `Wukong` resolves to `MonkeyKing` and `Nunu & Willump` to `Nunu`. Names
+    /// are compared ignoring case, spaces and punctuation, so `nunu willump`
+    /// and `Kai'Sa` / `kaisa` also resolve. Ids are accepted as well.
+    pub fn key_for_name(&self, display_name: &str) -> Option<&str> {
+        let wanted = normalize_name(display_name);
+
+        self.data
+            .iter()
+            .find(|(_, champion)| champion.name == display_name)
+            .or_else(|| {
+                self.data.iter().find(|(_, champion)| normalize_name(&champion.name) == wanted)
+            })
+            .or_else(|| self.data.iter().find(|(key, _)| normalize_name(key) == wanted))
+            .map(|(key, _)| key.as_str())
+    }
+
+    /// Builds a [ChampionIndex] for constant time lookups by numeric key or
+    /// name. Build it once and keep it around when doing many lookups, rather
+    /// than scanning the dataset each time.
+    pub fn build_index(&self) -> ChampionIndex {
+        let mut index = ChampionIndex::default();
+        for (id, champion) in &self.data {
+            if let Ok(key) = champion.key.parse() {
+                index.by_key.insert(key, id.clone());
+            }
+            index.by_name.insert(champion.name.to_lowercase(), id.clone());
+        }
+        index
+    }
+
+    /// Searches champions by name, id, or a common nickname from the built-in
+    /// [ChampionAliases], so `mundo`, `yi` and `mf` find Dr. Mundo, Master Yi
+    /// and Miss Fortune. See [search_with](Self::search_with) to use extra
+    /// aliases.
+    pub fn search_with_aliases(&self, query: &str) -> Vec<&ChampionShort> {
+        self.search_with(query, &ChampionAliases::default())
+    }
+
+    /// Searches champions by name, id, or any alias in `aliases`. Names and
+    /// ids match if they contain the query, and aliases match exactly, all
+    /// ignoring case, spaces and punctuation. Results are sorted by name.
+    pub fn search_with(&self, query: &str, aliases: &ChampionAliases) -> Vec<&ChampionShort> {
+        let wanted = normalize_name(query);
+        if wanted.is_empty() {
+            return Vec::new();
+        }
+        let aliased = aliases.aliases.get(&wanted);
+
+        let mut matches: Vec<&ChampionShort> = self
+            .data
+            .iter()
+            .filter(|(key, champion)| {
+                aliased.is_some_and(|id| id.eq_ignore_ascii_case(key))
+                    || normalize_name(&champion.name).contains(&wanted)
+                    || normalize_name(key).contains(&wanted)
+            })
+            .map(|(_, champion)| champion)
+            .collect();
+        matches.sort_unstable_by(|a, b| a.name.cmp(&b.name));
+        matches
+    }
+
+    /// Returns the `n` champions with the highest value of a base stat, highest
+    /// first. Ties are broken by id so the order is deterministic, and
+    /// champions that don't list the stat are skipped.
+    pub fn top_by_stat(&self, stat: ChampionStat, n: usize) -> Vec<&ChampionShort> {
+        self.sorted_by(stat, true)
+            .into_iter()
+            .take_while(|champion| champion.stat(stat).is_some())
+            .take(n)
+            .collect()
+    }
+
+    /// Returns every champion sorted by a base stat, highest first if
+    /// `descending`. Ties are broken by id in either direction so the order is
+    /// deterministic, and champions that don't list the stat come last.
+    pub fn sorted_by(&self, stat: ChampionStat, descending: bool) -> Vec<&ChampionShort> {
+        let mut sorted: Vec<(&ChampionShort, Option<f64>)> =
+            self.data.values().map(|champion| (champion, champion.stat(stat))).collect();
+        sorted.sort_by(|(a, a_value), (b, b_value)| {
+            let by_value = match (a_value, b_value) {
+                (Some(a_value), Some(b_value)) if descending => b_value.total_cmp(a_value),
+                (Some(a_value), Some(b_value)) => a_value.total_cmp(b_value),
+                (a_value, b_value) => b_value.is_some().cmp(&a_value.is_some()),
+            };
+            by_value.then_with(|| a.id.cmp(&b.id))
+        });
+        sorted.into_iter().map(|(champion, _)| champion).collect()
+    }
+
+    /// Returns the ranged champions, as classified by
+    /// [is_ranged](ChampionShort::is_ranged), sorted by id.
+    pub fn ranged(&self) -> Vec<&ChampionShort> {
+        self.by_attack_range(|range| range > RANGED_ATTACK_RANGE)
+    }
+
+    /// Returns the melee champions, those whose base attack range is at most
+    /// [RANGED_ATTACK_RANGE], sorted by id. Champions that don't list their
+    /// attack range are in neither this nor [ranged](Self::ranged).
+    pub fn melee(&self) -> Vec<&ChampionShort> {
+        self.by_attack_range(|range| range <= RANGED_ATTACK_RANGE)
+    }
+
+    /// Partitions every champion by the resource their abilities use, with
+    /// each group sorted by id. Resources [ResourceType] doesn't know are
+    /// grouped under [Other](ResourceType::Other), so no champion is dropped.
+    pub fn grouped_by_resource(&self) -> HashMap<ResourceType, Vec<&ChampionShort>> {
+        let mut groups: HashMap<ResourceType, Vec<&ChampionShort>> = HashMap::new();
+        for champion in self.data.values() {
+            groups.entry(champion.resource_type()).or_default().push(champion);
+        }
+        for champions in groups.values_mut() {
+            champions.sort_by(|a, b| a.id.cmp(&b.id));
+        }
+        groups
+    }
+
+    fn by_attack_range(&self, predicate: impl Fn(f64) -> bool) -> Vec<&ChampionShort> {
+        let mut champions: Vec<&ChampionShort> = self
+            .data
+            .values()
+            .filter(|champion| champion.stat(ChampionStat::AttackRange).is_some_and(&predicate))
+            .collect();
+        champions.sort_by(|a, b| a.id.cmp(&b.id));
+        champions
+    }
+
+    /// Compares this dataset against a newer one, reporting which ids were
+    /// added, removed, or changed in `other`. The per-champion `version` is
+    /// ignored, since it changes every patch.
+    pub fn diff(&self, other: &Champions) -> DataDiff {
+        diff_data_by(&self.data, &other.data, |old, new| {
+            if old.version == new.version {
+                old == new
+            } else {
+                ChampionShort { version: new.version.clone(), ..old.clone() } == *new
+            }
+        })
+    }
+
+    /// Returns the champions whose data differs from an older snapshot,
+    /// sorted by id. As with [diff](Self::diff), the per-champion `version`
+    /// is ignored, and champions added since the snapshot aren't included.
+    pub fn changed_champions_since(&self, old: &Champions) -> Vec<&ChampionShort> {
+        old.diff(self).changed.iter().filter_map(|id| self.data.get(id)).collect()
+    }
+}
+
+/// Lookup tables from a champion's numeric key and name to its id, as built
+/// by [Champions::build_index].
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct ChampionIndex {
+    /// Champion ids by numeric key, e.x. `62` to `MonkeyKing`.
+    pub by_key: HashMap<i64, String>,
+    /// Champion ids by lowercase name, e.x. `wukong` to `MonkeyKing`.
+    pub by_name: HashMap<String, String>,
+}
+
+impl ChampionIndex {
+    /// The id of the champion with the given numeric key, as used by match
+    /// data.
+    pub fn id_for_key(&self, key: i64) -> Option<&str> {
+        self.by_key.get(&key).map(String::as_str)
+    }
+
+    /// The id of the champion with the given name, ignoring case.
+    pub fn id_for_name(&self, name: &str) -> Option<&str> {
+        self.by_name.get(&name.to_lowercase()).map(String::as_str)
+    }
+}
+
+/// Lowercases a name and drops anything but letters and digits, so that
+/// `Kai'Sa`, `kai sa` and `kaisa` compare equal.
+fn normalize_name(value: &str) -> String {
+    value.chars().filter(|c| c.is_alphanumeric()).flat_map(char::to_lowercase).collect()
+}
+
+/// Nicknames for champions, used by [Champions::search_with]. The default
+/// table covers common community nicknames, and more can be added with
+/// [insert](Self::insert).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ChampionAliases {
+    aliases: HashMap<String, String>,
+}
+
+impl ChampionAliases {
+    const BUILT_IN: &'static [(&'static str, &'static str)] = &[
+        ("asol", "AurelionSol"),
+        ("cait", "Caitlyn"),
+        ("cass", "Cassiopeia"),
+        ("cho", "Chogath"),
+        ("gp", "Gangplank"),
+        ("heimer", "Heimerdinger"),
+        ("j4", "JarvanIV"),
+        ("kog", "KogMaw"),
+        ("lb", "Leblanc"),
+        ("liss", "Lissandra"),
+        ("mf", "MissFortune"),
+        ("morg", "Morgana"),
+        ("naut", "Nautilus"),
+        ("noc", "Nocturne"),
+        ("tf", "TwistedFate"),
+        ("trist", "Tristana"),
+        ("voli", "Volibear"),
+        ("wukong", "MonkeyKing"),
+        ("ww", "Warwick"),
+        ("yi", "MasterYi"),
+    ];
+
+    /// Creates an empty alias table, without the built-in nicknames.
+    pub fn empty() -> Self {
+        Self { aliases: HashMap::new() }
+    }
+
+    /// Adds an alias for the champion with the given id, e.x. `Chogath`.
+    /// Aliases are compared ignoring case, spaces and punctuation, and
+    /// replace any existing alias with the same spelling.
+    pub fn insert(&mut self, alias: &str, champion_id: &str) {
+        self.aliases.insert(normalize_name(alias), champion_id.to_owned());
+    }
+
+    /// The id of the champion an alias refers to, if any.
+    pub fn get(&self, alias: &str) -> Option<&str> {
+        self.aliases.get(&normalize_name(alias)).map(String::as_str)
+    }
+}
+
+impl Default for ChampionAliases {
+    fn default() -> Self {
+        let mut aliases = Self::empty();
+        for (alias, champion_id) in Self::BUILT_IN {
+            aliases.insert(alias, champion_id);
+        }
+        aliases
+    }
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
@@ -20,6 +298,7 @@ pub struct ChampionShort {
     pub key: String,
     pub name: String,
     pub title: String,
+    #[serde(default)]
     pub blurb: String,
     pub info: Info,
     pub image: Image,
@@ -29,3 +308,223 @@ pub struct ChampionShort {
 }
 
 has_image!(ChampionShort);
+champion_data!(ChampionShort);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::models::champion::Lane;
+
+    fn champions() -> Champions {
+        let champion = |id: &str, name: &str, armor: f64| {
+            serde_json::json!({
+                "version": "0.0.0",
+                "id": id,
+                "key": "0",
+                "name": name,
+                "title": "",
+                "info": { "attack": 0, "defense": 0, "magic": 0, "difficulty": 0 },
+                "image": {
+                    "full": format!("{id}.png"),
+                    "sprite": "champion0.png",
+                    "group": "champion",
+                    "x": 0, "y": 0, "w": 48, "h": 48
+                },
+                "tags": [],
+                "partype": "",
+                "stats": { "armor": armor }
+            })
+        };
+        let json = serde_json::json!({
+            "format": "standAloneComplex",
+            "version": "0.0.0",
+            "data": {
+                "MonkeyKing": champion("MonkeyKing", "Wukong", 31.0),
+                "Nunu": champion("Nunu", "Nunu & Willump", 29.0),
+                "Kaisa": champion("Kaisa", "Kai'Sa", 29.0),
+            }
+        });
+        Champions::from_json(json.to_string().as_bytes()).unwrap()
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn random_with_seed_is_stable() {
+        use rand::{rngs::StdRng, SeedableRng};
+
+        let champions = champions();
+        let pick =
+            |seed| champions.random_with(&mut StdRng::seed_from_u64(seed)).unwrap().id.clone();
+
+        assert_eq!(pick(7), pick(7));
+        assert!(champions.data.contains_key(&pick(7)));
+        assert!(champions.random().is_some());
+    }
+
+    #[test]
+    fn search_with_aliases_matches_names_and_nicknames() {
+        let champions = champions();
+        let ids = |found: Vec<&ChampionShort>| -> Vec<String> {
+            found.into_iter().map(|champion| champion.id.clone()).collect()
+        };
+
+        assert_eq!(ids(champions.search_with_aliases("wukong")), ["MonkeyKing"]);
+        assert_eq!(ids(champions.search_with_aliases("willump")), ["Nunu"]);
+        assert!(champions.search_with_aliases("").is_empty());
+
+        let mut aliases = ChampionAliases::default();
+        aliases.insert("Daughter of the Void", "Kaisa");
+        assert_eq!(ids(champions.search_with("daughter of the void", &aliases)), ["Kaisa"]);
+        assert_eq!(aliases.get("wu kong"), Some("MonkeyKing"));
+    }
+
+    #[test]
+    fn build_index_maps_keys_and_names_to_ids() {
+        let mut champions = champions();
+        champions.data.get_mut("MonkeyKing").unwrap().key = "62".to_owned();
+        champions.data.get_mut("Kaisa").unwrap().key = "145".to_owned();
+
+        let index = champions.build_index();
+        assert_eq!(index.id_for_key(62), Some("MonkeyKing"));
+        assert_eq!(index.id_for_key(145), Some("Kaisa"));
+        assert_eq!(index.id_for_name("NUNU & WILLUMP"), Some("Nunu"));
+        assert_eq!(index.id_for_name("Nunu"), None);
+    }
+
+    #[test]
+    fn key_for_name_resolves_special_cases() {
+        let champions = champions();
+        assert_eq!(champions.key_for_name("Wukong"), Some("MonkeyKing"));
+        assert_eq!(champions.key_for_name("Nunu & Willump"), Some("Nunu"));
+    }
+
+    #[test]
+    fn key_for_name_ignores_case_and_punctuation() {
+        let champions = champions();
+        assert_eq!(champions.key_for_name("nunu willump"), Some("Nunu"));
+        assert_eq!(champions.key_for_name("kaisa"), Some("Kaisa"));
+        assert_eq!(champions.key_for_name("monkeyking"), Some("MonkeyKing"));
+        assert_eq!(champions.key_for_name("Teemo"), None);
+    }
+
+    #[test]
+    fn top_by_stat_sorts_descending_with_stable_ties() {
+        let champions = champions();
+        let ids = |n| {
+            champions
+                .top_by_stat(ChampionStat::Armor, n)
+                .into_iter()
+                .map(|champion| champion.id.as_str())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(ids(3), ["MonkeyKing", "Kaisa", "Nunu"]);
+        assert_eq!(ids(1), ["MonkeyKing"]);
+        assert!(champions.top_by_stat(ChampionStat::Crit, 3).is_empty());
+    }
+
+    #[test]
+    fn sorted_by_orders_every_champion() {
+        let mut champions = champions();
+        champions.data.get_mut("Nunu").unwrap().stats.remove("armor");
+        fn ids(champions: Vec<&ChampionShort>) -> Vec<&str> {
+            champions.into_iter().map(|champion| champion.id.as_str()).collect()
+        }
+
+        assert_eq!(
+            ids(champions.sorted_by(ChampionStat::Armor, true)),
+            ["MonkeyKing", "Kaisa", "Nunu"]
+        );
+        assert_eq!(
+            ids(champions.sorted_by(ChampionStat::Armor, false)),
+            ["Kaisa", "MonkeyKing", "Nunu"]
+        );
+        assert_eq!(
+            ids(champions.sorted_by(ChampionStat::Crit, true)),
+            ["Kaisa", "MonkeyKing", "Nunu"]
+        );
+    }
+
+    #[test]
+    fn grouped_by_resource_keeps_every_champion() {
+        let mut champions = champions();
+        champions.data.get_mut("MonkeyKing").unwrap().partype = "Mana".to_owned();
+        champions.data.get_mut("Nunu").unwrap().partype = "Mana".to_owned();
+        champions.data.get_mut("Kaisa").unwrap().partype = "Sanity".to_owned();
+
+        let groups = champions.grouped_by_resource();
+        let ids = |resource: &ResourceType| -> Vec<&str> {
+            groups[resource].iter().map(|champion| champion.id.as_str()).collect()
+        };
+        assert_eq!(groups.len(), 2);
+        assert_eq!(ids(&ResourceType::Mana), ["MonkeyKing", "Nunu"]);
+        assert_eq!(ids(&ResourceType::Other("Sanity".to_owned())), ["Kaisa"]);
+    }
+
+    #[test]
+    fn resource_summary_scales_with_level() {
+        let mut champions = champions();
+        assert_eq!(champions.data["Kaisa"].resource_summary(), None);
+
+        let kaisa = champions.data.get_mut("Kaisa").unwrap();
+        kaisa.partype = "Mana".to_owned();
+        kaisa.stats.extend([
+            ("mp".to_owned(), 345.0),
+            ("mpperlevel".to_owned(), 40.0),
+            ("mpregen".to_owned(), 8.2),
+        ]);
+
+        let summary = kaisa.resource_summary().unwrap();
+        assert_eq!(summary.resource, ResourceType::Mana);
+        assert_eq!(summary.max_at_level(1), 345.0);
+        assert_eq!(summary.max_at_level(18), 1025.0);
+        assert_eq!(summary.regen_at_level(18), 8.2);
+    }
+
+    #[test]
+    fn ranged_and_melee_split_on_attack_range() {
+        let mut champions = champions();
+        let mut set_range = |id: &str, range: f64| {
+            champions.data.get_mut(id).unwrap().stats.insert("attackrange".to_owned(), range);
+        };
+        set_range("Kaisa", 525.0);
+        set_range("MonkeyKing", 175.0);
+
+        fn ids(champions: Vec<&ChampionShort>) -> Vec<&str> {
+            champions.into_iter().map(|champion| champion.id.as_str()).collect()
+        }
+        assert_eq!(ids(champions.ranged()), ["Kaisa"]);
+        assert_eq!(ids(champions.melee()), ["MonkeyKing"]);
+        assert!(champions.data["Kaisa"].is_ranged());
+        assert!(!champions.data["Nunu"].is_ranged());
+    }
+
+    #[test]
+    fn likely_lanes_follow_tags_and_range() {
+        let mut champions = champions();
+        let kaisa = champions.data.get_mut("Kaisa").unwrap();
+        kaisa.tags = vec![Tag::Marksman, Tag::Assassin];
+        kaisa.stats.insert("attackrange".to_owned(), 525.0);
+        assert_eq!(kaisa.likely_lanes(), [Lane::Bot, Lane::Mid, Lane::Jungle]);
+
+        let nunu = champions.data.get_mut("Nunu").unwrap();
+        nunu.tags = vec![Tag::Tank, Tag::Marksman];
+        assert_eq!(nunu.likely_lanes(), [Lane::Top, Lane::Support, Lane::Jungle]);
+
+        let hints: &[(Tag, &[Lane])] = &[(Tag::Tank, &[Lane::Jungle])];
+        assert_eq!(nunu.likely_lanes_with(hints), [Lane::Jungle]);
+    }
+
+    #[test]
+    fn changed_champions_since_returns_current_entries() {
+        let old = champions();
+        let mut new = champions();
+        new.data.get_mut("Nunu").unwrap().stats.insert("armor".to_owned(), 32.0);
+        new.data.get_mut("Kaisa").unwrap().version = "0.0.1".to_owned();
+
+        let changed = new.changed_champions_since(&old);
+        assert_eq!(changed.len(), 1);
+        assert_eq!(changed[0].id, "Nunu");
+        assert_eq!(changed[0].stat(ChampionStat::Armor), Some(32.0));
+    }
+}
@@ -1,12 +1,207 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
-use super::Champion;
+use super::{
+    champion::{ChampionData, ResourceType, SpellSlot, Tag},
+    shared::{
+        diff_data, from_json, get_ignore_case, has_data, unique_filenames, DataDiff, DataMap, Image,
+    },
+    Champion,
+};
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 pub struct ChampionsFull {
     pub format: String,
     pub version: String,
-    pub data: HashMap<String, Champion>,
+    pub data: DataMap<Champion>,
     pub keys: HashMap<String, String>,
 }
+
+has_data!(ChampionsFull);
+from_json!(ChampionsFull);
+
+impl ChampionsFull {
+    /// Returns the champion with the given id. If there's no exact match, the
+    /// id is matched case-insensitively instead, which covers ids whose casing
+    /// has changed over time (e.g. `FiddleSticks` vs. `Fiddlesticks`).
+    pub fn get(&self, id: &str) -> Option<&Champion> {
+        get_ignore_case(&self.data, id)
+    }
+
+    /// Compares this dataset against a newer one, reporting which ids were
+    /// added, removed, or changed in `other`.
+    pub fn diff(&self, other: &ChampionsFull) -> DataDiff {
+        diff_data(&self.data, &other.data)
+    }
+
+    /// Returns the champions whose data differs from an older snapshot,
+    /// sorted by id, e.x. to detect stat and ability changes in a new patch.
+    /// Champions added since the snapshot are reported by [diff](Self::diff)
+    /// instead.
+    pub fn changed_champions_since(&self, old: &ChampionsFull) -> Vec<&Champion> {
+        old.diff(self).changed.iter().filter_map(|id| self.data.get(id)).collect()
+    }
+
+    /// Compares this dataset against a newer one and reports champions whose
+    /// changes look like a rework rather than a balance tweak, sorted by id.
+    /// A champion counts as reworked when any of these changed:
+    ///
+    /// - the `id` of the spell in any slot, including spells added or removed
+    /// - its resource type, e.x. from mana to energy
+    /// - its tags, including their order, as the first tag is its main role
+    ///
+    /// Number changes like stats, cooldowns and costs are never reported, and
+    /// neither are champions added or removed, which [diff](Self::diff)
+    /// covers.
+    pub fn detect_reworks(&self, other: &ChampionsFull) -> Vec<ReworkReport> {
+        let mut reports: Vec<ReworkReport> = self
+            .data
+            .iter()
+            .filter_map(|(id, old)| {
+                let changes = rework_changes(old, other.data.get(id)?);
+                (!changes.is_empty()).then(|| ReworkReport { id: id.clone(), changes })
+            })
+            .collect();
+        reports.sort_by(|a, b| a.id.cmp(&b.id));
+        reports
+    }
+
+    /// The `full` filename of every image referenced by this dataset,
+    /// including each champion's passive and spells, sorted and without
+    /// duplicates.
+    pub fn image_filenames(&self) -> Vec<&str> {
+        unique_filenames(self.images().map(|image| image.full.as_str()))
+    }
+
+    /// The filename of every sprite sheet referenced by this dataset,
+    /// including those of passives and spells, sorted and without duplicates.
+    pub fn sprite_filenames(&self) -> Vec<&str> {
+        unique_filenames(self.images().map(|image| image.sprite.as_str()))
+    }
+
+    fn images(&self) -> impl Iterator<Item = &Image> {
+        self.data.values().flat_map(|champion| {
+            std::iter::once(&champion.image)
+                .chain(std::iter::once(&champion.passive.image))
+                .chain(champion.spells.iter().map(|spell| &spell.image))
+        })
+    }
+}
+
+/// A champion flagged by [ChampionsFull::detect_reworks], with every change
+/// that made it look like a rework.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ReworkReport {
+    pub id: String,
+    pub changes: Vec<ReworkChange>,
+}
+
+/// One kind of change that marks a champion as a rework candidate. `None`
+/// means the champion had no spell in that slot.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub enum ReworkChange {
+    Spell { slot: SpellSlot, old: Option<String>, new: Option<String> },
+    Resource { old: ResourceType, new: ResourceType },
+    Tags { old: Vec<Tag>, new: Vec<Tag> },
+}
+
+fn rework_changes(old: &Champion, new: &Champion) -> Vec<ReworkChange> {
+    let spell_id = |champion: &Champion, slot| champion.spell(slot).map(|spell| spell.id.clone());
+    let mut changes: Vec<ReworkChange> = SpellSlot::ALL
+        .into_iter()
+        .filter_map(|slot| {
+            let (old, new) = (spell_id(old, slot), spell_id(new, slot));
+            (old != new).then_some(ReworkChange::Spell { slot, old, new })
+        })
+        .collect();
+
+    let (old_resource, new_resource) = (old.resource_type(), new.resource_type());
+    if old_resource != new_resource {
+        changes.push(ReworkChange::Resource { old: old_resource, new: new_resource });
+    }
+    if old.tags != new.tags {
+        changes.push(ReworkChange::Tags { old: old.tags.clone(), new: new.tags.clone() });
+    }
+    changes
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::models::champion::test::spell;
+
+    fn champions_full() -> ChampionsFull {
+        let spells =
+            ["AhriQ", "AhriW", "AhriE", "AhriR"].map(|id| spell(serde_json::json!({ "id": id })));
+        let image = |full: &str, group: &str| {
+            serde_json::json!({
+                "full": full,
+                "sprite": format!("{group}0.png"),
+                "group": group,
+                "x": 0, "y": 0, "w": 48, "h": 48
+            })
+        };
+        let json = serde_json::json!({
+            "format": "full",
+            "version": "0.0.0",
+            "data": {
+                "Ahri": {
+                    "id": "Ahri",
+                    "key": "103",
+                    "name": "Ahri",
+                    "title": "the Nine-Tailed Fox",
+                    "image": image("Ahri.png", "champion"),
+                    "skins": [],
+                    "allytips": [],
+                    "enemytips": [],
+                    "tags": ["Mage", "Assassin"],
+                    "partype": "Mana",
+                    "info": { "attack": 3, "defense": 4, "magic": 8, "difficulty": 5 },
+                    "stats": { "hp": 590.0 },
+                    "spells": spells,
+                    "passive": {
+                        "name": "Essence Theft",
+                        "description": "",
+                        "image": image("Ahri_SoulEater2.png", "passive")
+                    }
+                }
+            },
+            "keys": { "103": "Ahri" }
+        });
+        ChampionsFull::from_json(json.to_string().as_bytes()).unwrap()
+    }
+
+    #[test]
+    fn reworks_flag_ability_resource_and_role_changes() {
+        let old = champions_full();
+        let mut new = old.clone();
+        assert!(old.detect_reworks(&new).is_empty());
+
+        new.data.get_mut("Ahri").unwrap().stats.insert("hp".to_owned(), 1000.0);
+        assert!(old.detect_reworks(&new).is_empty());
+
+        let ahri = new.data.get_mut("Ahri").unwrap();
+        ahri.spells[0].id = "AhriNewQ".to_owned();
+        ahri.partype = "Energy".to_owned();
+        ahri.tags.reverse();
+
+        let reports = old.detect_reworks(&new);
+        assert_eq!(reports.len(), 1);
+        assert_eq!(reports[0].id, "Ahri");
+        assert_eq!(
+            reports[0].changes,
+            [
+                ReworkChange::Spell {
+                    slot: SpellSlot::Q,
+                    old: Some("AhriQ".to_owned()),
+                    new: Some("AhriNewQ".to_owned()),
+                },
+                ReworkChange::Resource { old: ResourceType::Mana, new: ResourceType::Energy },
+                ReworkChange::Tags {
+                    old: vec![Tag::Mage, Tag::Assassin],
+                    new: vec![Tag::Assassin, Tag::Mage],
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,65 @@
+//! Turns stat numbers into display strings for a given ddragon locale, e.x.
+//! `0,625` instead of `0.625` for `de_DE`. Nothing in the models formats
+//! numbers on its own, so these are only used when called explicitly.
+
+/// The ddragon locales that write decimals with a comma.
+const COMMA_DECIMAL_LOCALES: &[&str] = &[
+    "cs_CZ", "de_DE", "el_GR", "es_AR", "es_ES", "fr_FR", "hu_HU", "id_ID", "it_IT", "pl_PL",
+    "pt_BR", "ro_RO", "ru_RU", "tr_TR", "vi_VN",
+];
+
+/// The character used to separate the whole and fractional parts of a
+/// number in the given locale, e.x. `','` for `fr_FR`. Unknown locales use
+/// `'.'`, like ddragon's own data.
+pub fn decimal_separator(locale: &str) -> char {
+    if COMMA_DECIMAL_LOCALES.contains(&locale) {
+        ','
+    } else {
+        '.'
+    }
+}
+
+/// Formats a stat number for the given locale, rounded to at most three
+/// decimal places and without trailing zeros, e.x. `0,625` or `40` for
+/// `de_DE`.
+pub fn format_number(value: f64, locale: &str) -> String {
+    let rounded = (value * 1000.0).round() / 1000.0;
+    // Avoids printing `-0` for tiny negative values.
+    let rounded = if rounded == 0.0 { 0.0 } else { rounded };
+    let formatted = rounded.to_string();
+    match decimal_separator(locale) {
+        '.' => formatted,
+        separator => formatted.replace('.', &separator.to_string()),
+    }
+}
+
+/// Formats per-rank values as a "burn" string for the given locale, e.x.
+/// `0,5/0,75/1` for `de_DE`. Like ddragon's own burn strings, values that are
+/// the same at every rank are only written once.
+pub fn format_burn(values: &[f64], locale: &str) -> String {
+    match values.first() {
+        Some(first) if values.iter().all(|value| value == first) => format_number(*first, locale),
+        _ => values.iter().map(|value| format_number(*value, locale)).collect::<Vec<_>>().join("/"),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn numbers_use_the_locale_decimal_separator() {
+        assert_eq!(format_number(0.625, "en_US"), "0.625");
+        assert_eq!(format_number(0.625, "de_DE"), "0,625");
+        assert_eq!(format_number(40.0, "fr_FR"), "40");
+        assert_eq!(format_number(0.1 + 0.2, "ko_KR"), "0.3");
+        assert_eq!(format_number(-0.0001, "en_US"), "0");
+    }
+
+    #[test]
+    fn burns_collapse_equal_ranks() {
+        assert_eq!(format_burn(&[0.5, 0.75, 1.0], "pt_BR"), "0,5/0,75/1");
+        assert_eq!(format_burn(&[12.0, 12.0, 12.0], "en_US"), "12");
+        assert_eq!(format_burn(&[], "en_US"), "");
+    }
+}
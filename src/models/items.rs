@@ -1,97 +1,222 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
-use super::shared::{has_image, Image};
+use super::shared::{
+    diff_data, from_json, has_data, has_image, has_images, number_from_string,
+    option_number_from_string, DataDiff, DataMap, Image,
+};
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 pub struct Items {
     pub version: String,
-    pub data: HashMap<String, Item>,
+    pub data: DataMap<Item>,
+    #[serde(default)]
     pub groups: Vec<Group>,
+    #[serde(default)]
     pub tree: Vec<Tree>,
 }
 
+has_data!(Items);
+has_images!(Items);
+from_json!(Items);
+
+impl Items {
+    /// Whether an item with the given id exists in this patch.
+    pub fn contains(&self, id: &str) -> bool {
+        self.data.contains_key(id)
+    }
+
+    /// Returns the ids from `ids` that don't exist in this patch, such as
+    /// items referenced by older match data that have since been removed.
+    pub fn missing<'a, I>(&self, ids: I) -> Vec<&'a str>
+    where
+        I: IntoIterator<Item = &'a str>,
+    {
+        ids.into_iter().filter(|id| !self.contains(id)).collect()
+    }
+
+    /// Checks that every id listed in an item's `from` and `into` exists in
+    /// this dataset, returning every dangling reference sorted by item id
+    /// rather than stopping at the first.
+    pub fn validate_references(&self) -> Vec<ReferenceError> {
+        let mut errors: Vec<ReferenceError> = self
+            .data
+            .iter()
+            .flat_map(|(item_id, item)| {
+                let from = item.from.iter().flatten().map(|id| (ReferenceField::From, id));
+                let into = item.into.iter().flatten().map(|id| (ReferenceField::Into, id));
+                from.chain(into).filter(|(_, id)| !self.contains(id)).map(|(field, id)| {
+                    ReferenceError { item_id: item_id.clone(), field, missing_id: id.clone() }
+                })
+            })
+            .collect();
+        errors.sort_unstable_by(|a, b| {
+            (&a.item_id, a.field, &a.missing_id).cmp(&(&b.item_id, b.field, &b.missing_id))
+        });
+        errors
+    }
+
+    /// Returns the items usable by the champion with the given id, e.x.
+    /// `Kalista`, leaving out items locked to a different champion through
+    /// [required_champion](Item::required_champion). Items with a
+    /// [required_ally](Item::required_ally), such as Ornn's masterwork
+    /// upgrades, are kept, as any champion can get them with that ally on
+    /// their team.
+    pub fn for_champion(&self, champion_id: &str) -> Vec<&Item> {
+        self.data.values().filter(|item| item.is_available_to(champion_id)).collect()
+    }
+
+    /// Compares this dataset against a newer one, reporting which ids were
+    /// added, removed, or changed in `other`.
+    pub fn diff(&self, other: &Items) -> DataDiff {
+        diff_data(&self.data, &other.data)
+    }
+}
+
+/// An item id listed in another item's recipe that doesn't exist in the
+/// dataset, as returned by [Items::validate_references].
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ReferenceError {
+    /// The item whose recipe lists the missing id.
+    pub item_id: String,
+    /// Which side of the recipe the missing id was listed in.
+    pub field: ReferenceField,
+    /// The id that couldn't be found.
+    pub missing_id: String,
+}
+
+/// The recipe field of an item a [ReferenceError] was found in.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ReferenceField {
+    /// The components the item is built from.
+    From,
+    /// The items the item builds into.
+    Into,
+}
+
 #[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
 pub struct Gold {
+    #[serde(deserialize_with = "number_from_string")]
     pub base: i64,
+    #[serde(deserialize_with = "number_from_string")]
     pub total: i64,
+    #[serde(deserialize_with = "number_from_string")]
     pub sell: i64,
     pub purchasable: bool,
 }
 
+impl Gold {
+    /// The cost of the item including the cost of all its components.
+    pub fn total_gold(&self) -> i64 {
+        self.total
+    }
+
+    /// The gold received for selling the item.
+    pub fn sell_value(&self) -> i64 {
+        self.sell
+    }
+
+    /// The cost of the item itself, excluding the cost of its components.
+    pub fn combine_cost(&self) -> i64 {
+        self.base
+    }
+
+    /// Returns the gold efficiency, as a percentage, of an item whose stats
+    /// are worth `stats_value` gold. Items which cost nothing have an
+    /// efficiency of 0.
+    pub fn efficiency(&self, stats_value: f64) -> f64 {
+        if self.total <= 0 {
+            return 0.0;
+        }
+
+        stats_value / self.total as f64 * 100.0
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 pub struct Item {
     pub name: String,
     pub description: String,
+    #[serde(default)]
     pub colloq: String,
+    #[serde(default)]
     pub plaintext: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub into: Option<Vec<String>>,
     pub image: Image,
     pub gold: Gold,
+    #[serde(default)]
     pub tags: Vec<String>,
+    #[serde(default)]
     pub maps: HashMap<String, bool>,
+    #[serde(default)]
     pub stats: HashMap<String, f64>,
     #[serde(rename = "inStore")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub in_store: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub from: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub effect: Option<Effect>,
+    #[serde(default, deserialize_with = "option_number_from_string")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub depth: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub consumed: Option<bool>,
+    #[serde(default, deserialize_with = "option_number_from_string")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub stacks: Option<i64>,
     #[serde(rename = "hideFromAll")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub hide_from_all: Option<bool>,
     #[serde(rename = "consumeOnFull")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub consume_on_full: Option<bool>,
     #[serde(rename = "requiredChampion")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub required_champion: Option<String>,
     #[serde(rename = "requiredAlly")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub required_ally: Option<String>,
     #[serde(rename = "specialRecipe")]
+    #[serde(default, deserialize_with = "option_number_from_string")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub special_recipe: Option<i64>,
 }
 
 has_image!(Item);
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
-pub struct Effect {
-    #[serde(rename = "Effect1Amount")]
-    pub effect1_amount: String,
-    #[serde(rename = "Effect2Amount")]
-    pub effect2_amount: Option<String>,
-    #[serde(rename = "Effect3Amount")]
-    pub effect3_amount: Option<String>,
-    #[serde(rename = "Effect4Amount")]
-    pub effect4_amount: Option<String>,
-    #[serde(rename = "Effect5Amount")]
-    pub effect5_amount: Option<String>,
-    #[serde(rename = "Effect6Amount")]
-    pub effect6_amount: Option<String>,
-    #[serde(rename = "Effect7Amount")]
-    pub effect7_amount: Option<String>,
-    #[serde(rename = "Effect8Amount")]
-    pub effect8_amount: Option<String>,
-    #[serde(rename = "Effect9Amount")]
-    pub effect9_amount: Option<String>,
-    #[serde(rename = "Effect10Amount")]
-    pub effect10_amount: Option<String>,
-    #[serde(rename = "Effect11Amount")]
-    pub effect11_amount: Option<String>,
-    #[serde(rename = "Effect12Amount")]
-    pub effect12_amount: Option<String>,
-    #[serde(rename = "Effect13Amount")]
-    pub effect13_amount: Option<String>,
-    #[serde(rename = "Effect14Amount")]
-    pub effect14_amount: Option<String>,
-    #[serde(rename = "Effect15Amount")]
-    pub effect15_amount: Option<String>,
-    #[serde(rename = "Effect16Amount")]
-    pub effect16_amount: Option<String>,
-    #[serde(rename = "Effect17Amount")]
-    pub effect17_amount: Option<String>,
-    #[serde(rename = "Effect18Amount")]
-    pub effect18_amount: Option<String>,
+impl Item {
+    /// The cost of the item including the cost of all its components.
+    pub fn total_gold(&self) -> i64 {
+        self.gold.total_gold()
+    }
+
+    /// The gold received for selling the item.
+    pub fn sell_value(&self) -> i64 {
+        self.gold.sell_value()
+    }
+
+    /// The `n`th effect value (`EffectNAmount`, counting from 1), if the item
+    /// has one and it's numeric.
+    pub fn effect_amount(&self, n: usize) -> Option<f64> {
+        self.effect.as_ref()?.get(&format!("Effect{n}Amount"))?.trim().parse().ok()
+    }
+
+    /// Whether the champion with the given id can use this item, i.e. the
+    /// item isn't locked to some other champion through `requiredChampion`.
+    /// A `requiredAlly` doesn't restrict who can use the item, only which
+    /// ally has to be on the team.
+    pub fn is_available_to(&self, champion_id: &str) -> bool {
+        self.required_champion.as_ref().map_or(true, |required| required == champion_id)
+    }
 }
 
+/// An item's effect values, keyed `Effect1Amount` through `EffectNAmount`.
+/// The number of keys varies between items, so they're kept as a map; see
+/// [Item::effect_amount] for parsed access.
+pub type Effect = HashMap<String, String>;
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct Group {
     pub id: String,
@@ -104,3 +229,150 @@ pub struct Tree {
     pub header: String,
     pub tags: Vec<String>,
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn items_json() -> serde_json::Value {
+        let item = |name: &str, required: Option<(&str, &str)>| {
+            let mut item = serde_json::json!({
+                "name": name,
+                "description": "",
+                "colloq": "",
+                "plaintext": "",
+                "image": {
+                    "full": "1001.png",
+                    "sprite": "item0.png",
+                    "group": "item",
+                    "x": 0, "y": 0, "w": 48, "h": 48
+                },
+                "gold": { "base": 0, "total": 0, "sell": 0, "purchasable": true },
+                "tags": [],
+                "maps": {},
+                "stats": {}
+            });
+            if let Some((field, champion)) = required {
+                item[field] = champion.into();
+            }
+            item
+        };
+        serde_json::json!({
+            "version": "0.0.0",
+            "data": {
+                "1001": item("Boots", None),
+                "3599": item("Kalista's Black Spear", Some(("requiredChampion", "Kalista"))),
+                "7000": item("Sandshrike's Claw", Some(("requiredAlly", "Ornn"))),
+            },
+            "groups": [],
+            "tree": []
+        })
+    }
+
+    fn items() -> Items {
+        Items::from_json(items_json().to_string().as_bytes()).unwrap()
+    }
+
+    fn names(items: Vec<&Item>) -> Vec<&str> {
+        let mut names: Vec<_> = items.into_iter().map(|item| item.name.as_str()).collect();
+        names.sort_unstable();
+        names
+    }
+
+    #[test]
+    fn serializing_omits_absent_fields() {
+        assert_eq!(serde_json::to_value(items()).unwrap(), items_json());
+    }
+
+    #[test]
+    fn validate_references_reports_every_dangling_id() {
+        let mut items = items();
+        assert!(items.validate_references().is_empty());
+
+        let boots = items.data.get_mut("1001").unwrap();
+        boots.into = Some(vec!["3599".to_owned(), "9999".to_owned()]);
+        boots.from = Some(vec!["8888".to_owned()]);
+
+        let missing: Vec<_> = items
+            .validate_references()
+            .into_iter()
+            .map(|error| (error.item_id, error.field, error.missing_id))
+            .collect();
+        assert_eq!(
+            missing,
+            [
+                ("1001".to_owned(), ReferenceField::From, "8888".to_owned()),
+                ("1001".to_owned(), ReferenceField::Into, "9999".to_owned())
+            ]
+        );
+    }
+
+    #[test]
+    fn old_patch_items_parse_without_newer_fields() {
+        let items = Items::from_json(
+            br#"{
+                "version": "3.7.1",
+                "data": {
+                    "1001": {
+                        "name": "Boots of Speed",
+                        "description": "Slightly increases Movement Speed",
+                        "image": {
+                            "full": "1001.png",
+                            "sprite": "item0.png",
+                            "group": "item",
+                            "x": 0, "y": 0, "w": 48, "h": 48
+                        },
+                        "gold": { "base": 325, "total": 325, "sell": 227, "purchasable": true }
+                    }
+                }
+            }"#,
+        )
+        .unwrap();
+
+        let boots = &items.data["1001"];
+        assert!(boots.colloq.is_empty() && boots.maps.is_empty() && boots.stats.is_empty());
+        assert!(items.groups.is_empty() && items.tree.is_empty());
+    }
+
+    #[test]
+    fn effect_amount_parses_numbered_effects() {
+        let mut json = items_json();
+        json["data"]["1001"]["effect"] = serde_json::json!({
+            "Effect1Amount": "10",
+            "Effect2Amount": "0.5",
+            "Effect3Amount": "",
+            "Effect21Amount": "-3"
+        });
+        let items = Items::from_json(json.to_string().as_bytes()).unwrap();
+
+        let boots = &items.data["1001"];
+        assert_eq!(boots.effect_amount(1), Some(10.0));
+        assert_eq!(boots.effect_amount(2), Some(0.5));
+        assert_eq!(boots.effect_amount(3), None);
+        assert_eq!(boots.effect_amount(4), None);
+        assert_eq!(boots.effect_amount(21), Some(-3.0));
+        assert_eq!(items.data["3599"].effect_amount(1), None);
+    }
+
+    #[test]
+    fn image_and_sprite_filenames_are_deduplicated() {
+        let items = items();
+        assert_eq!(items.image_filenames(), ["1001.png"]);
+        assert_eq!(items.sprite_filenames(), ["item0.png"]);
+    }
+
+    #[test]
+    fn for_champion_keeps_unrestricted_items() {
+        assert_eq!(names(items().for_champion("Ahri")), vec!["Boots", "Sandshrike's Claw"]);
+    }
+
+    #[test]
+    fn for_champion_includes_own_items() {
+        let items = items();
+        assert_eq!(
+            names(items.for_champion("Kalista")),
+            vec!["Boots", "Kalista's Black Spear", "Sandshrike's Claw"]
+        );
+        assert_eq!(names(items.for_champion("Ornn")), vec!["Boots", "Sandshrike's Claw"]);
+    }
+}
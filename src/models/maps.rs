@@ -1,14 +1,17 @@
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
 
-use super::shared::{has_image, Image};
+use super::shared::{from_json, has_data, has_image, has_images, DataMap, Image};
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct Maps {
     pub version: String,
-    pub data: HashMap<String, Map>,
+    pub data: DataMap<Map>,
 }
 
+has_data!(Maps);
+has_images!(Maps);
+from_json!(Maps);
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct Map {
     #[serde(rename = "MapName")]
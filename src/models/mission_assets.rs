@@ -1,16 +1,22 @@
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
 
-use super::shared::{has_image, Image};
+use super::shared::{
+    from_json, has_data, has_image, has_images, number_from_string, DataMap, Image,
+};
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct MissionAssets {
     pub version: String,
-    pub data: HashMap<String, MissionAsset>,
+    pub data: DataMap<MissionAsset>,
 }
 
+has_data!(MissionAssets);
+has_images!(MissionAssets);
+from_json!(MissionAssets);
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct MissionAsset {
+    #[serde(deserialize_with = "number_from_string")]
     pub id: i64,
     pub image: Image,
 }
@@ -2,13 +2,16 @@ pub mod challenges;
 pub mod champion;
 pub mod champions;
 pub mod champions_full;
+pub mod format;
 pub mod items;
 pub mod maps;
 pub mod mission_assets;
 pub mod profile_icons;
+pub mod realm;
 pub mod runes;
 pub mod shared;
 pub mod spell_buffs;
+pub mod stickers;
 pub mod summoner_spells;
 pub mod tft;
 pub mod translations;
@@ -21,7 +24,9 @@ pub use items::Items;
 pub use maps::Maps;
 pub use mission_assets::MissionAssets;
 pub use profile_icons::ProfileIcons;
+pub use realm::Realm;
 pub use runes::Runes;
 pub use spell_buffs::SpellBuffs;
+pub use stickers::Stickers;
 pub use summoner_spells::SummonerSpells;
 pub use translations::Translations;
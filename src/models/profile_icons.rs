@@ -1,14 +1,17 @@
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
 
-use super::shared::{has_image, Image};
+use super::shared::{from_json, has_data, has_image, has_images, DataMap, Image};
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 pub struct ProfileIcons {
     pub version: String,
-    pub data: HashMap<String, ProfileIcon>,
+    pub data: DataMap<ProfileIcon>,
 }
 
+has_data!(ProfileIcons);
+has_images!(ProfileIcons);
+from_json!(ProfileIcons);
+
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 pub struct ProfileIcon {
     pub id: Id,
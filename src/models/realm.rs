@@ -0,0 +1,78 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use super::shared::from_json;
+
+/// The versions a region is serving, from `realms/{region}.json`. Regions
+/// can lag behind the global latest version while a patch rolls out.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Realm {
+    /// The version of each dataset, e.x. `item` or `champion`.
+    pub n: HashMap<String, String>,
+    /// The current version of the region's data.
+    pub v: String,
+    /// The region's default locale.
+    pub l: String,
+    /// The base URL of the CDN serving the region's data.
+    pub cdn: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dd: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lg: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub css: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profileiconmax: Option<i64>,
+}
+
+from_json!(Realm);
+
+impl Realm {
+    /// The Data Dragon version served to this region, which is the version to
+    /// use for region-accurate data.
+    pub fn dd_version(&self) -> Option<&str> {
+        self.dd.as_deref()
+    }
+
+    /// The League client version in this region.
+    pub fn lg_version(&self) -> Option<&str> {
+        self.lg.as_deref()
+    }
+
+    /// The version of the client stylesheets in this region.
+    pub fn css_version(&self) -> Option<&str> {
+        self.css.as_deref()
+    }
+
+    /// The version of a single dataset in this region, e.x. `item`.
+    pub fn dataset_version(&self, dataset: &str) -> Option<&str> {
+        self.n.get(dataset).map(String::as_str)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn versions_parse_with_some_absent() {
+        let realm = Realm::from_json(
+            br#"{
+                "n": { "item": "14.1.1", "champion": "14.1.1" },
+                "v": "14.1.1",
+                "l": "en_US",
+                "cdn": "https://ddragon.leagueoflegends.com/cdn",
+                "dd": "14.1.1",
+                "lg": "14.1.2",
+                "profileiconmax": 28,
+                "store": null
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(realm.dd_version(), Some("14.1.1"));
+        assert_eq!(realm.lg_version(), Some("14.1.2"));
+        assert_eq!(realm.css_version(), None);
+        assert_eq!(realm.dataset_version("item"), Some("14.1.1"));
+    }
+}
@@ -1,9 +1,12 @@
 use serde::{Deserialize, Serialize};
 
+use super::shared::number_from_string;
+
 pub type Runes = Vec<Rune>;
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct Rune {
+    #[serde(deserialize_with = "number_from_string")]
     pub id: i64,
     pub key: String,
     pub icon: String,
@@ -18,6 +21,7 @@ pub struct Slot {
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct RuneElement {
+    #[serde(deserialize_with = "number_from_string")]
     pub id: i64,
     pub key: String,
     pub icon: String,
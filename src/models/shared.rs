@@ -1,13 +1,18 @@
-use serde::{Deserialize, Serialize};
+use serde::{de, Deserialize, Deserializer, Serialize};
+use std::{fmt::Display, str::FromStr};
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct Image {
     pub full: String,
     pub sprite: String,
     pub group: String,
+    #[serde(deserialize_with = "number_from_string")]
     pub x: i64,
+    #[serde(deserialize_with = "number_from_string")]
     pub y: i64,
+    #[serde(deserialize_with = "number_from_string")]
     pub w: i64,
+    #[serde(deserialize_with = "number_from_string")]
     pub h: i64,
 }
 
@@ -18,6 +23,18 @@ impl Image {
     pub fn sprite_path(&self) -> String {
         format!("sprite/{}", self.sprite)
     }
+
+    /// Cuts this image out of its (already downloaded) sprite sheet.
+    #[cfg(feature = "image")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "image")))]
+    pub fn crop_from(&self, sprite: &image::DynamicImage) -> image::DynamicImage {
+        sprite.crop_imm(
+            self.x.max(0) as u32,
+            self.y.max(0) as u32,
+            self.w.max(0) as u32,
+            self.h.max(0) as u32,
+        )
+    }
 }
 
 pub trait HasImage {
@@ -49,6 +66,70 @@ macro_rules! has_image {
 }
 pub(crate) use has_image;
 
+macro_rules! has_data {
+    ($s:ident) => {
+        $crate::models::shared::has_data!($s, data);
+    };
+    ($s:ident, $field:ident) => {
+        impl $s {
+            /// The number of entries in this dataset.
+            pub fn len(&self) -> usize {
+                self.$field.len()
+            }
+
+            /// Whether this dataset has no entries.
+            pub fn is_empty(&self) -> bool {
+                self.$field.is_empty()
+            }
+        }
+    };
+}
+pub(crate) use has_data;
+
+macro_rules! from_json {
+    ($s:ident) => {
+        impl $s {
+            /// Parses this dataset from raw ddragon JSON, such as a file that was
+            /// fetched ahead of time or bundled with an application.
+            pub fn from_json(bytes: &[u8]) -> Result<Self, serde_json::Error> {
+                serde_json::from_slice(bytes)
+            }
+        }
+    };
+}
+pub(crate) use from_json;
+
+macro_rules! has_images {
+    ($s:ident) => {
+        impl $s {
+            /// The `full` filename of every image referenced by this dataset,
+            /// sorted and without duplicates.
+            pub fn image_filenames(&self) -> Vec<&str> {
+                $crate::models::shared::unique_filenames(
+                    self.data.values().map(|entry| entry.image.full.as_str()),
+                )
+            }
+
+            /// The filename of every sprite sheet referenced by this dataset,
+            /// sorted and without duplicates, as many images share a sheet.
+            pub fn sprite_filenames(&self) -> Vec<&str> {
+                $crate::models::shared::unique_filenames(
+                    self.data.values().map(|entry| entry.image.sprite.as_str()),
+                )
+            }
+        }
+    };
+}
+pub(crate) use has_images;
+
+/// Sorts and deduplicates filenames for the `has_images!` listings.
+pub(crate) fn unique_filenames<'a>(filenames: impl Iterator<Item = &'a str>) -> Vec<&'a str> {
+    let mut filenames: Vec<&str> = filenames.collect();
+    filenames.sort_unstable();
+    filenames.dedup();
+    filenames
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 pub struct BasicDatum {
     pub id: String,
@@ -57,3 +138,481 @@ pub struct BasicDatum {
 }
 
 has_image!(BasicDatum);
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum NumberOrString<T> {
+    Number(T),
+    String(String),
+}
+
+impl<T> NumberOrString<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    fn into_number<E: de::Error>(self) -> Result<T, E> {
+        match self {
+            NumberOrString::Number(number) => Ok(number),
+            NumberOrString::String(string) => string.trim().parse().map_err(E::custom),
+        }
+    }
+}
+
+/// Deserializes a number which ddragon may send either as a number or as a
+/// numeric string (e.g. `5` or `"5"`).
+pub(crate) fn number_from_string<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de> + FromStr,
+    T::Err: Display,
+{
+    NumberOrString::<T>::deserialize(deserializer)?.into_number()
+}
+
+/// Like [number_from_string], for optional fields. Fields using this also need
+/// `#[serde(default)]` so they may be omitted entirely.
+pub(crate) fn option_number_from_string<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de> + FromStr,
+    T::Err: Display,
+{
+    Option::<NumberOrString<T>>::deserialize(deserializer)?
+        .map(NumberOrString::into_number)
+        .transpose()
+}
+
+/// Deserializes a field ddragon usually sends as a string, but sometimes as a
+/// number, `null` or an object (e.g. `maxammo`). Numbers keep their textual
+/// form, `null` becomes an empty string and anything else is kept as JSON.
+pub(crate) fn string_from_any<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(match serde_json::Value::deserialize(deserializer)? {
+        serde_json::Value::String(string) => string,
+        serde_json::Value::Null => String::new(),
+        other => other.to_string(),
+    })
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum OneOrMany<T> {
+    One(T),
+    Many(Vec<T>),
+}
+
+/// Deserializes a list which ddragon may send as a single bare value when it
+/// has only one entry (e.g. `0.5` or `[0.5, 0.6]`).
+pub(crate) fn one_or_many<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    Ok(match OneOrMany::<T>::deserialize(deserializer)? {
+        OneOrMany::One(value) => vec![value],
+        OneOrMany::Many(values) => values,
+    })
+}
+
+/// The map each dataset keeps its entries in. With the `preserve_order`
+/// feature enabled this is an [IndexMap](indexmap::IndexMap), so iterating
+/// and re-serializing follows ddragon's own key order.
+#[cfg(not(feature = "preserve_order"))]
+pub type DataMap<V> = std::collections::HashMap<String, V>;
+
+/// The map each dataset keeps its entries in. With the `preserve_order`
+/// feature enabled this is an [IndexMap](indexmap::IndexMap), so iterating
+/// and re-serializing follows ddragon's own key order.
+#[cfg(feature = "preserve_order")]
+pub type DataMap<V> = indexmap::IndexMap<String, V>;
+
+/// The keys which differ between two versions of the same dataset.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct DataDiff {
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+    pub changed: Vec<String>,
+}
+
+impl DataDiff {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// Compares an older `data` map against a newer one. Keys in each list of the
+/// resulting [DataDiff] are sorted.
+pub(crate) fn diff_data<T: PartialEq>(old: &DataMap<T>, new: &DataMap<T>) -> DataDiff {
+    diff_data_by(old, new, |a, b| a == b)
+}
+
+/// Like [diff_data], but with a custom equality check for entries that carry
+/// fields which always change between patches.
+pub(crate) fn diff_data_by<T>(
+    old: &DataMap<T>,
+    new: &DataMap<T>,
+    eq: impl Fn(&T, &T) -> bool,
+) -> DataDiff {
+    let mut diff = DataDiff::default();
+
+    for (key, new_value) in new {
+        match old.get(key) {
+            None => diff.added.push(key.clone()),
+            Some(old_value) if !eq(old_value, new_value) => diff.changed.push(key.clone()),
+            Some(_) => {}
+        }
+    }
+    diff.removed.extend(old.keys().filter(|key| !new.contains_key(*key)).cloned());
+
+    diff.added.sort();
+    diff.removed.sort();
+    diff.changed.sort();
+    diff
+}
+
+/// Strips the HTML-like markup ddragon uses in descriptions and lore, turning
+/// line breaks into newlines and decoding the common entities.
+pub fn strip_html(text: &str) -> String {
+    convert_markup(text, |tag| if tag.eq_ignore_ascii_case("br") { "\n" } else { "" })
+}
+
+/// Converts the HTML-like markup ddragon uses in descriptions and tooltips to
+/// Markdown, e.x. for Discord embeds. Recognized tags are:
+///
+/// - `<br>` becomes a newline.
+/// - `<b>` and `<strong>` become bold.
+/// - `<i>` and `<em>` become italic.
+/// - Damage and effect highlights, such as `<magicDamage>`, `<physicalDamage>`,
+///   `<trueDamage>`, `<status>`, `<healing>`, `<shield>` and `<speed>`, become
+///   bold.
+/// - Scaling highlights, any tag starting with `scale` such as `<scaleAP>`,
+///   become italic.
+///
+/// Any other tag is removed, and nested bold or italic tags are merged so
+/// the output never doubles up markers. Entities are decoded as in
+/// [strip_html].
+pub fn html_to_markdown(text: &str) -> String {
+    const BOLD_TAGS: &[&str] = &[
+        "b",
+        "strong",
+        "magicdamage",
+        "physicaldamage",
+        "truedamage",
+        "status",
+        "healing",
+        "shield",
+        "speed",
+    ];
+    let (mut bold, mut italic) = (0usize, 0usize);
+
+    convert_markup(text, |tag| {
+        let (closing, name) = match tag.strip_prefix('/') {
+            Some(name) => (true, name.to_ascii_lowercase()),
+            None => (false, tag.to_ascii_lowercase()),
+        };
+        let (depth, marker) = if BOLD_TAGS.contains(&name.as_str()) {
+            (&mut bold, "**")
+        } else if name == "i" || name == "em" || name.starts_with("scale") {
+            (&mut italic, "*")
+        } else {
+            return if name == "br" && !closing { "\n" } else { "" };
+        };
+
+        // Only the outermost tag of each kind emits a marker.
+        if closing {
+            *depth = depth.saturating_sub(1);
+            if *depth == 0 {
+                return marker;
+            }
+        } else {
+            *depth += 1;
+            if *depth == 1 {
+                return marker;
+            }
+        }
+        ""
+    })
+}
+
+/// Walks ddragon's markup, replacing each tag with whatever `on_tag` returns
+/// for its name (prefixed with `/` for closing tags), and decodes the common
+/// entities in the result.
+fn convert_markup(text: &str, mut on_tag: impl FnMut(&str) -> &'static str) -> String {
+    let mut converted = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find('<') {
+        converted.push_str(&rest[..start]);
+        match rest[start..].find('>') {
+            Some(end) => {
+                let tag = rest[start + 1..start + end].trim().trim_end_matches('/');
+                converted.push_str(on_tag(tag.split_whitespace().next().unwrap_or_default()));
+                rest = &rest[start + end + 1..];
+            }
+            None => {
+                converted.push_str(&rest[start..]);
+                rest = "";
+            }
+        }
+    }
+    converted.push_str(rest);
+
+    converted
+        .replace("&nbsp;", " ")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+/// Looks up `key` in a `data` map, falling back to a case-insensitive match
+/// when there's no exact match.
+pub(crate) fn get_ignore_case<'a, T>(data: &'a DataMap<T>, key: &str) -> Option<&'a T> {
+    data.get(key).or_else(|| {
+        data.iter()
+            .find(|(candidate, _)| candidate.eq_ignore_ascii_case(key))
+            .map(|(_, value)| value)
+    })
+}
+
+/// Parses a ddragon "burn" string (e.g. `"12/11/10/9/8"`) into its per-rank
+/// values. Segments that aren't numeric (e.g. `"self"`) become `None`.
+pub(crate) fn parse_burn(burn: &str) -> Vec<Option<f64>> {
+    if burn.trim().is_empty() {
+        return vec![];
+    }
+
+    burn.split('/').map(|value| value.trim().parse::<f64>().ok()).collect()
+}
+
+/// Combines parsed burn values with the matching numeric array. The burn
+/// values are what tooltips display, so they win whenever they're present; a
+/// single burn value applies to every rank. Ranks with neither value are
+/// `None`, so every value stays at its rank's index.
+pub(crate) fn reconcile_burn(burn: &[Option<f64>], values: &[f64]) -> Vec<Option<f64>> {
+    (0..burn.len().max(values.len()))
+        .map(|rank| {
+            let burn_value =
+                if burn.len() == 1 { burn[0] } else { burn.get(rank).copied().flatten() };
+            burn_value.or_else(|| values.get(rank).copied())
+        })
+        .collect()
+}
+
+/// Looks up a value in a spell's `effect` array, where `index` counts as
+/// tooltips do (`e1` is index 1, as index 0 is always `null`) and `rank`
+/// counts from 1. An effect listing a single value applies to every rank.
+pub(crate) fn effect_at(effect: &[Option<Vec<f64>>], index: usize, rank: usize) -> Option<f64> {
+    if index == 0 || rank == 0 {
+        return None;
+    }
+
+    match effect.get(index)?.as_deref()? {
+        [value] => Some(*value),
+        values => values.get(rank - 1).copied(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[derive(Deserialize, Debug, PartialEq)]
+    struct Numbers {
+        #[serde(deserialize_with = "number_from_string")]
+        count: i64,
+        #[serde(deserialize_with = "number_from_string")]
+        ratio: f64,
+        #[serde(default, deserialize_with = "option_number_from_string")]
+        depth: Option<i64>,
+    }
+
+    #[test]
+    fn number_from_string_accepts_numbers() {
+        assert_eq!(
+            serde_json::from_str::<Numbers>(r#"{"count": 5, "ratio": 0.5, "depth": 2}"#).unwrap(),
+            Numbers { count: 5, ratio: 0.5, depth: Some(2) }
+        );
+    }
+
+    #[test]
+    fn number_from_string_accepts_numeric_strings() {
+        assert_eq!(
+            serde_json::from_str::<Numbers>(r#"{"count": "5", "ratio": " 0.5", "depth": "2"}"#)
+                .unwrap(),
+            Numbers { count: 5, ratio: 0.5, depth: Some(2) }
+        );
+    }
+
+    #[test]
+    fn number_from_string_optional_may_be_missing_or_null() {
+        assert_eq!(
+            serde_json::from_str::<Numbers>(r#"{"count": 1, "ratio": 1}"#).unwrap().depth,
+            None
+        );
+        assert_eq!(
+            serde_json::from_str::<Numbers>(r#"{"count": 1, "ratio": 1, "depth": null}"#)
+                .unwrap()
+                .depth,
+            None
+        );
+    }
+
+    #[test]
+    fn number_from_string_rejects_non_numeric_strings() {
+        assert!(serde_json::from_str::<Numbers>(r#"{"count": "five", "ratio": 1}"#).is_err());
+    }
+
+    #[test]
+    fn image_accepts_string_coordinates() {
+        let image = serde_json::from_str::<Image>(
+            r#"{"full": "Ahri.png", "sprite": "champion0.png", "group": "champion", "x": "48", "y": 0, "w": "48", "h": 48}"#,
+        )
+        .unwrap();
+        assert_eq!((image.x, image.w), (48, 48));
+    }
+
+    #[test]
+    fn diff_data_reports_added_removed_and_changed() {
+        let old = DataMap::from([
+            ("Aatrox".to_owned(), 1),
+            ("Ahri".to_owned(), 2),
+            ("Akali".to_owned(), 3),
+        ]);
+        let new = DataMap::from([
+            ("Ahri".to_owned(), 2),
+            ("Akali".to_owned(), 4),
+            ("Zeri".to_owned(), 5),
+            ("Ambessa".to_owned(), 6),
+        ]);
+
+        let diff = diff_data(&old, &new);
+        assert_eq!(diff.added, vec!["Ambessa".to_owned(), "Zeri".to_owned()]);
+        assert_eq!(diff.removed, vec!["Aatrox".to_owned()]);
+        assert_eq!(diff.changed, vec!["Akali".to_owned()]);
+    }
+
+    #[test]
+    fn diff_data_identical_is_empty() {
+        let data = DataMap::from([("Ahri".to_owned(), 2)]);
+        assert!(diff_data(&data, &data.clone()).is_empty());
+    }
+
+    #[test]
+    fn strip_html_removes_tags() {
+        assert_eq!(
+            strip_html("Deals <magicDamage>80 magic damage</magicDamage>.<br><br>Heals."),
+            "Deals 80 magic damage.\n\nHeals."
+        );
+    }
+
+    #[test]
+    fn strip_html_decodes_entities_and_keeps_unclosed_text() {
+        assert_eq!(strip_html("Nunu &amp; Willump<br/>"), "Nunu & Willump\n");
+        assert_eq!(strip_html("1 < 2"), "1 < 2");
+    }
+
+    #[test]
+    fn html_to_markdown_styles_recognized_tags() {
+        assert_eq!(
+            html_to_markdown(
+                "Deals <magicDamage>80 (+<scaleAP>60% AP</scaleAP>)</magicDamage>.<br /><i>Passive:</i> <font color='#fff'>Heals</font>."
+            ),
+            "Deals **80 (+*60% AP*)**.\n*Passive:* Heals."
+        );
+        assert_eq!(
+            html_to_markdown("<b><status>Stuns</status></b> &amp; slows"),
+            "**Stuns** & slows"
+        );
+    }
+
+    #[test]
+    fn get_ignore_case_prefers_exact_match() {
+        let data = DataMap::from([("FiddleSticks".to_owned(), 1), ("Fiddlesticks".to_owned(), 2)]);
+        assert_eq!(get_ignore_case(&data, "FiddleSticks"), Some(&1));
+        assert_eq!(get_ignore_case(&data, "Fiddlesticks"), Some(&2));
+    }
+
+    #[test]
+    fn get_ignore_case_falls_back_to_any_casing() {
+        let data = DataMap::from([("Fiddlesticks".to_owned(), 1)]);
+        assert_eq!(get_ignore_case(&data, "FiddleSticks"), Some(&1));
+        assert_eq!(get_ignore_case(&data, "Zoe"), None);
+    }
+
+    #[test]
+    fn parse_burn_splits_ranks() {
+        assert_eq!(
+            parse_burn("12/11/10/9/8"),
+            vec![Some(12.0), Some(11.0), Some(10.0), Some(9.0), Some(8.0)]
+        );
+    }
+
+    #[test]
+    fn parse_burn_non_numeric_is_none() {
+        assert_eq!(parse_burn("self"), vec![None]);
+        assert_eq!(parse_burn("0.5/x"), vec![Some(0.5), None]);
+        assert!(parse_burn("").is_empty());
+    }
+
+    #[test]
+    fn reconcile_burn_prefers_burn_values() {
+        assert_eq!(
+            reconcile_burn(&[Some(12.0), Some(11.5), Some(11.0)], &[12.0, 11.0, 11.0]),
+            vec![Some(12.0), Some(11.5), Some(11.0)]
+        );
+    }
+
+    #[test]
+    fn reconcile_burn_single_value_applies_to_all_ranks() {
+        assert_eq!(
+            reconcile_burn(&[Some(5.0)], &[0.0, 0.0, 0.0]),
+            vec![Some(5.0), Some(5.0), Some(5.0)]
+        );
+        assert_eq!(reconcile_burn(&parse_burn("7"), &[]), vec![Some(7.0)]);
+    }
+
+    #[test]
+    fn reconcile_burn_falls_back_to_numeric_values() {
+        assert_eq!(reconcile_burn(&[None], &[550.0, 600.0]), vec![Some(550.0), Some(600.0)]);
+        assert_eq!(reconcile_burn(&[], &[1.0, 2.0]), vec![Some(1.0), Some(2.0)]);
+    }
+
+    #[test]
+    fn reconcile_burn_keeps_gaps_in_place() {
+        assert_eq!(reconcile_burn(&parse_burn("10/x/8"), &[]), vec![Some(10.0), None, Some(8.0)]);
+        assert_eq!(
+            reconcile_burn(&parse_burn("10/x/8"), &[10.0, 9.0]),
+            vec![Some(10.0), Some(9.0), Some(8.0)]
+        );
+    }
+
+    #[cfg(feature = "preserve_order")]
+    #[test]
+    fn data_map_preserves_source_order() {
+        let json = r#"{"Zed":1,"Ahri":2,"Mordekaiser":3}"#;
+        let data: DataMap<i64> = serde_json::from_str(json).unwrap();
+
+        assert_eq!(data.keys().collect::<Vec<_>>(), ["Zed", "Ahri", "Mordekaiser"]);
+        assert_eq!(serde_json::to_string(&data).unwrap(), json);
+    }
+
+    #[test]
+    fn has_data_counts_entries() {
+        struct Dataset {
+            data: DataMap<i64>,
+        }
+        has_data!(Dataset);
+
+        let mut dataset = Dataset { data: DataMap::new() };
+        assert!(dataset.is_empty());
+
+        dataset.data.insert("1001".to_owned(), 300);
+        assert_eq!(dataset.len(), 1);
+        assert!(!dataset.is_empty());
+    }
+}
@@ -1,13 +1,19 @@
 use serde::{Deserialize, Serialize};
 
+use super::shared::{from_json, has_data, number_from_string};
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct SpellBuffs {
     #[serde(rename = "spellBuffs")]
     pub spell_buffs: Vec<SpellBuff>,
 }
 
+has_data!(SpellBuffs, spell_buffs);
+from_json!(SpellBuffs);
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct SpellBuff {
+    #[serde(deserialize_with = "number_from_string")]
     pub id: i64,
     pub name: String,
 }
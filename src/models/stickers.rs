@@ -0,0 +1,24 @@
+use serde::{Deserialize, Serialize};
+
+use super::shared::{
+    from_json, has_data, has_image, has_images, number_from_string, DataMap, Image,
+};
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Stickers {
+    pub version: String,
+    pub data: DataMap<Sticker>,
+}
+
+has_data!(Stickers);
+has_images!(Stickers);
+from_json!(Stickers);
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Sticker {
+    #[serde(deserialize_with = "number_from_string")]
+    pub id: i64,
+    pub image: Image,
+}
+
+has_image!(Sticker);
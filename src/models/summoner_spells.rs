@@ -1,12 +1,42 @@
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
 
-use super::shared::{has_image, Image};
+use super::shared::{
+    effect_at, from_json, has_data, has_image, has_images, number_from_string, string_from_any,
+    DataMap, Image,
+};
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 pub struct SummonerSpells {
     pub version: String,
-    pub data: HashMap<String, SummonerSpell>,
+    pub data: DataMap<SummonerSpell>,
+}
+
+has_data!(SummonerSpells);
+has_images!(SummonerSpells);
+from_json!(SummonerSpells);
+
+impl SummonerSpells {
+    /// Returns the summoner spells unlocked by an account at the given level,
+    /// according to each spell's [summoner_level](SummonerSpell::summoner_level).
+    pub fn available_at_level(&self, level: i64) -> Vec<&SummonerSpell> {
+        self.data.values().filter(|spell| spell.summoner_level <= level).collect()
+    }
+
+    /// Returns the summoner spells usable on Summoner's Rift, i.e. those whose
+    /// [modes](SummonerSpell::modes) include `CLASSIC`, sorted by id.
+    ///
+    /// Other mode strings seen in ddragon include `ARAM`, `URF`, `ONEFORALL`,
+    /// `NEXUSBLITZ`, `ULTBOOK`, `CHERRY` (Arena), `TUTORIAL` and
+    /// `PRACTICETOOL`. Most spells list several modes.
+    pub fn summoners_rift_only(&self) -> Vec<&SummonerSpell> {
+        let mut spells: Vec<&SummonerSpell> = self
+            .data
+            .values()
+            .filter(|spell| spell.modes.iter().any(|mode| mode == "CLASSIC"))
+            .collect();
+        spells.sort_by(|a, b| a.id.cmp(&b.id));
+        spells
+    }
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
@@ -15,6 +45,7 @@ pub struct SummonerSpell {
     pub name: String,
     pub description: String,
     pub tooltip: String,
+    #[serde(deserialize_with = "number_from_string")]
     pub maxrank: i64,
     pub cooldown: Vec<f64>,
     #[serde(rename = "cooldownBurn")]
@@ -27,16 +58,79 @@ pub struct SummonerSpell {
     pub effect_burn: Vec<Option<String>>,
     pub key: String,
     #[serde(rename = "summonerLevel")]
+    #[serde(deserialize_with = "number_from_string")]
     pub summoner_level: i64,
+    #[serde(default)]
     pub modes: Vec<String>,
     #[serde(rename = "costType")]
     pub cost_type: String,
+    #[serde(default)]
+    #[serde(deserialize_with = "string_from_any")]
     pub maxammo: String,
     pub range: Vec<i64>,
     #[serde(rename = "rangeBurn")]
     pub range_burn: String,
     pub image: Image,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub resource: Option<String>,
 }
 
 has_image!(SummonerSpell);
+
+impl SummonerSpell {
+    /// The value of effect `e<effect_index>` at the given rank, counting from
+    /// 1, e.x. the damage Ignite deals. Index 0 is always empty, and an
+    /// effect with a single value applies to every rank.
+    pub fn effect_at(&self, effect_index: usize, rank: usize) -> Option<f64> {
+        effect_at(&self.effect, effect_index, rank)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn summoners_rift_only_requires_classic() {
+        let spell = |id: &str, modes: &[&str]| {
+            serde_json::json!({
+                "id": id,
+                "name": id,
+                "description": "",
+                "tooltip": "",
+                "maxrank": 1,
+                "cooldown": [],
+                "cooldownBurn": "",
+                "cost": [],
+                "costBurn": "",
+                "effect": [],
+                "effectBurn": [],
+                "key": "1",
+                "summonerLevel": 1,
+                "modes": modes,
+                "costType": "",
+                "range": [],
+                "rangeBurn": "",
+                "image": {
+                    "full": format!("{id}.png"),
+                    "sprite": "spell0.png",
+                    "group": "spell",
+                    "x": 0, "y": 0, "w": 48, "h": 48
+                }
+            })
+        };
+        let spells: SummonerSpells = serde_json::from_value(serde_json::json!({
+            "version": "0.0.0",
+            "data": {
+                "SummonerSmite": spell("SummonerSmite", &["URF", "CLASSIC"]),
+                "SummonerSnowball": spell("SummonerSnowball", &["ARAM"]),
+                "SummonerFlash": spell("SummonerFlash", &["CLASSIC", "ARAM"]),
+            }
+        }))
+        .unwrap();
+
+        let ids: Vec<&str> =
+            spells.summoners_rift_only().into_iter().map(|spell| spell.id.as_str()).collect();
+        assert_eq!(ids, ["SummonerFlash", "SummonerSmite"]);
+    }
+}
@@ -1,16 +1,21 @@
 use serde::{de::Visitor, Deserialize, Deserializer, Serialize};
-use std::collections::HashMap;
 
-use crate::models::shared::{has_image, BasicDatum, Image};
+use crate::models::shared::{
+    from_json, has_data, has_image, has_images, BasicDatum, DataMap, Image,
+};
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 pub struct Augments {
     pub version: String,
     #[serde(rename = "augment-container")]
     pub augment_container: AugmentContainer,
-    pub data: HashMap<String, BasicDatum>,
+    pub data: DataMap<BasicDatum>,
 }
 
+has_data!(Augments);
+has_images!(Augments);
+from_json!(Augments);
+
 #[derive(Serialize, Debug, Clone, PartialEq, Eq)]
 pub struct AugmentContainer {
     pub name: String,
@@ -1,20 +1,99 @@
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
 
-use crate::models::shared::{has_image, Image};
+use crate::models::shared::{
+    from_json, has_data, has_image, has_images, number_from_string, DataMap, Image,
+};
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 pub struct Champions {
     pub version: String,
-    pub data: HashMap<String, Champion>,
+    pub data: DataMap<Champion>,
+}
+
+has_data!(Champions);
+has_images!(Champions);
+from_json!(Champions);
+
+impl Champions {
+    /// The TFT set numbers present in this data, in ascending order. ddragon
+    /// only serves the current set's champions, but entries for an older set
+    /// are sometimes left in during set transitions.
+    pub fn sets(&self) -> Vec<u32> {
+        let mut sets: Vec<u32> = self.data.values().filter_map(Champion::set).collect();
+        sets.sort_unstable();
+        sets.dedup();
+        sets
+    }
+
+    /// The champions belonging to the given TFT set, sorted by id.
+    pub fn in_set(&self, set: u32) -> Vec<&Champion> {
+        let mut champions: Vec<&Champion> =
+            self.data.values().filter(|champion| champion.set() == Some(set)).collect();
+        champions.sort_by(|a, b| a.id.cmp(&b.id));
+        champions
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 pub struct Champion {
     pub id: String,
     pub name: String,
+    #[serde(deserialize_with = "number_from_string")]
     pub tier: i64,
     pub image: Image,
 }
 
 has_image!(Champion);
+
+impl Champion {
+    /// The TFT set this champion belongs to, read from the prefix of its id,
+    /// e.x. `13` for `TFT13_Akali`. `None` for ids without a set number, such
+    /// as tutorial or event units.
+    pub fn set(&self) -> Option<u32> {
+        let id = self.id.rsplit('/').next()?;
+        let digits = id.strip_prefix("TFT")?.split('_').next()?;
+        digits.parse().ok()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn champions_are_grouped_by_set() {
+        let champions: Champions = serde_json::from_value(serde_json::json!({
+                "version": "14.1.1",
+                "data": {
+                    "TFT10_Ahri": {
+                        "id": "TFT10_Ahri", "name": "Ahri", "tier": 4,
+                        "image": {
+                            "full": "TFT10_Ahri.png", "sprite": "tft-champion0.png",
+                            "group": "tft-champion", "x": 0, "y": 0, "w": 48, "h": 48
+                        }
+                    },
+                    "Maps/Shipping/Map22/Sets/TFTSet11/Shop/TFT11_Ahri": {
+                        "id": "TFT11_Ahri", "name": "Ahri", "tier": "3",
+                        "image": {
+                            "full": "TFT11_Ahri.png", "sprite": "tft-champion0.png",
+                            "group": "tft-champion", "x": 48, "y": 0, "w": 48, "h": 48
+                        }
+                    },
+                    "TFTTutorial_Garen": {
+                        "id": "TFTTutorial_Garen", "name": "Garen", "tier": 1,
+                        "image": {
+                            "full": "TFTTutorial_Garen.png", "sprite": "tft-champion0.png",
+                            "group": "tft-champion", "x": 96, "y": 0, "w": 48, "h": 48
+                        }
+                    }
+                }
+        }))
+        .unwrap();
+
+        assert_eq!(champions.sets(), [10, 11]);
+        let ids: Vec<&str> =
+            champions.in_set(11).iter().map(|champion| champion.id.as_str()).collect();
+        assert_eq!(ids, ["TFT11_Ahri"]);
+        assert_eq!(champions.data["TFTTutorial_Garen"].set(), None);
+    }
+}
@@ -1,14 +1,17 @@
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
 
-use crate::models::shared::{has_image, Image};
+use crate::models::shared::{from_json, has_data, has_image, has_images, DataMap, Image};
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct Queues {
     pub version: String,
-    pub data: HashMap<String, Queue>,
+    pub data: DataMap<Queue>,
 }
 
+has_data!(Queues);
+has_images!(Queues);
+from_json!(Queues);
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct Queue {
     pub id: String,
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::models::shared::{has_image, Image};
+use crate::models::shared::{from_json, has_image, Image};
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct Regalia {
@@ -8,6 +8,8 @@ pub struct Regalia {
     pub data: RegaliaData,
 }
 
+from_json!(Regalia);
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct RegaliaData {
     #[serde(rename = "RANKED_TFT")]
@@ -1,14 +1,17 @@
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
 
-use crate::models::shared::{has_image, Image};
+use crate::models::shared::{from_json, has_data, has_image, has_images, DataMap, Image};
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct Tacticians {
     pub version: String,
-    pub data: HashMap<String, Tactician>,
+    pub data: DataMap<Tactician>,
 }
 
+has_data!(Tacticians);
+has_images!(Tacticians);
+from_json!(Tacticians);
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct Tactician {
     pub id: String,
@@ -1,10 +1,13 @@
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
 
-use crate::models::shared::BasicDatum;
+use crate::models::shared::{from_json, has_data, has_images, BasicDatum, DataMap};
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct Traits {
     pub version: String,
-    pub data: HashMap<String, BasicDatum>,
+    pub data: DataMap<BasicDatum>,
 }
+
+has_data!(Traits);
+has_images!(Traits);
+from_json!(Traits);
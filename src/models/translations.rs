@@ -1,8 +1,12 @@
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+
+use super::shared::{from_json, has_data, DataMap};
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct Translations {
     pub version: String,
-    pub data: HashMap<String, String>,
+    pub data: DataMap<String>,
 }
+
+has_data!(Translations);
+from_json!(Translations);
@@ -0,0 +1,22 @@
+//! ```
+//! use ddragon::prelude::*;
+//! ```
+
+pub use crate::models::champion::ChampionData;
+pub use crate::models::shared::HasImage;
+pub use crate::models::{
+    Challenges, Champion, Champions, ChampionsFull, Items, Maps, MissionAssets, ProfileIcons,
+    Realm, Runes, SpellBuffs, Stickers, SummonerSpells, Translations,
+};
+
+#[cfg(any(feature = "sync", feature = "async-base"))]
+pub use crate::{ClientError, EmptyDatasets, FetchOptions, FetchStats};
+
+#[cfg(feature = "sync")]
+pub use crate::cache_middleware::{Cache, DiskCache};
+
+#[cfg(feature = "sync")]
+pub use crate::{Client, ClientBuilder};
+
+#[cfg(any(feature = "async", feature = "async-rustls"))]
+pub use crate::{AsyncClient, AsyncClientBuilder};
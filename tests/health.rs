@@ -11,6 +11,7 @@ fn health_check() {
     let _ = remove_dir_all(&tempdir);
 
     let client = Client::new(tempdir.as_os_str().to_str().unwrap()).unwrap();
+    assert!(client.realm("na").unwrap().dd_version().is_some());
 
     let uncached_start = Instant::now();
     let challenges = client.challenges().unwrap();
@@ -25,6 +26,7 @@ fn health_check() {
     let profile_icons = client.profile_icons().unwrap();
     let runes = client.runes().unwrap();
     let spell_buffs = client.spell_buffs().unwrap();
+    let stickers = client.stickers().unwrap();
     let summoner_spells = client.summoner_spells().unwrap();
     let translations = client.translations().unwrap();
     let tft_arenas = client.tft_arenas().unwrap();
@@ -51,6 +53,7 @@ fn health_check() {
     let cached_profile_icons = client.profile_icons().unwrap();
     let cached_runes = client.runes().unwrap();
     let cached_spell_buffs = client.spell_buffs().unwrap();
+    let cached_stickers = client.stickers().unwrap();
     let cached_summoner_spells = client.summoner_spells().unwrap();
     let cached_translations = client.translations().unwrap();
     let cached_tft_arenas = client.tft_arenas().unwrap();
@@ -81,6 +84,7 @@ fn health_check() {
     assert_eq!(profile_icons, cached_profile_icons);
     assert_eq!(runes, cached_runes);
     assert_eq!(spell_buffs, cached_spell_buffs);
+    assert_eq!(stickers, cached_stickers);
     assert_eq!(summoner_spells, cached_summoner_spells);
     assert_eq!(translations, cached_translations);
     assert_eq!(tft_arenas, cached_tft_arenas);
@@ -104,6 +108,7 @@ async fn async_health_check() {
     let _ = remove_dir_all(&tempdir);
 
     let client = AsyncClient::new(tempdir.as_os_str().to_str().unwrap()).await.unwrap();
+    assert!(client.realm("na").await.unwrap().dd_version().is_some());
 
     let uncached_start = Instant::now();
     let challenges = client.challenges().await.unwrap();
@@ -118,6 +123,7 @@ async fn async_health_check() {
     let profile_icons = client.profile_icons().await.unwrap();
     let runes = client.runes().await.unwrap();
     let spell_buffs = client.spell_buffs().await.unwrap();
+    let stickers = client.stickers().await.unwrap();
     let summoner_spells = client.summoner_spells().await.unwrap();
     let translations = client.translations().await.unwrap();
     let tft_arenas = client.tft_arenas().await.unwrap();
@@ -144,6 +150,7 @@ async fn async_health_check() {
     let cached_profile_icons = client.profile_icons().await.unwrap();
     let cached_runes = client.runes().await.unwrap();
     let cached_spell_buffs = client.spell_buffs().await.unwrap();
+    let cached_stickers = client.stickers().await.unwrap();
     let cached_summoner_spells = client.summoner_spells().await.unwrap();
     let cached_translations = client.translations().await.unwrap();
     let cached_tft_arenas = client.tft_arenas().await.unwrap();
@@ -174,6 +181,7 @@ async fn async_health_check() {
     assert_eq!(profile_icons, cached_profile_icons);
     assert_eq!(runes, cached_runes);
     assert_eq!(spell_buffs, cached_spell_buffs);
+    assert_eq!(stickers, cached_stickers);
     assert_eq!(summoner_spells, cached_summoner_spells);
     assert_eq!(translations, cached_translations);
     assert_eq!(tft_arenas, cached_tft_arenas);